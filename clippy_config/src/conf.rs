@@ -1,9 +1,9 @@
 use crate::ClippyConfiguration;
 use crate::types::{
-    DisallowedPath, DisallowedPathWithoutReplacement, MacroMatcher, MatchLintBehaviour, PubUnderscoreFieldsBehaviour,
-    Rename, SourceItemOrdering, SourceItemOrderingCategory, SourceItemOrderingModuleItemGroupings,
-    SourceItemOrderingModuleItemKind, SourceItemOrderingTraitAssocItemKind, SourceItemOrderingTraitAssocItemKinds,
-    SourceItemOrderingWithinModuleItemGroupings,
+    AnyPointerKind, DisallowedPath, DisallowedPathWithoutReplacement, MacroMatcher, MatchLintBehaviour,
+    PubUnderscoreFieldsBehaviour, Rename, SourceItemOrdering, SourceItemOrderingCategory,
+    SourceItemOrderingModuleItemGroupings, SourceItemOrderingModuleItemKind, SourceItemOrderingTraitAssocItemKind,
+    SourceItemOrderingTraitAssocItemKinds, SourceItemOrderingWithinModuleItemGroupings,
 };
 use clippy_utils::msrvs::Msrv;
 use itertools::Itertools;
@@ -50,6 +50,7 @@ const DEFAULT_DOC_VALID_IDENTS: &[&str] = &[
     "CamelCase",
 ];
 const DEFAULT_DISALLOWED_NAMES: &[&str] = &["foo", "baz", "quux"];
+const DEFAULT_ANY_PROPS_BAG_NAMES: &[&str] = &["props", "extra", "ctx", "data"];
 const DEFAULT_ALLOWED_IDENTS_BELOW_MIN_CHARS: &[&str] = &["i", "j", "x", "y", "z", "w", "n"];
 const DEFAULT_ALLOWED_PREFIXES: &[&str] = &["to", "as", "into", "from", "try_into", "try_from"];
 const DEFAULT_ALLOWED_TRAITS_WITH_RENAMED_PARAMS: &[&str] =
@@ -461,6 +462,12 @@ define_Conf! {
     /// are already allowed by default.
     #[lints(wildcard_imports)]
     allowed_wildcard_imports: Vec<String> = Vec::new(),
+    /// The names of parameters which are linted when their type is `Box<dyn Any>` or `&dyn Any`.
+    /// If the list is empty, all such parameters are linted. The value `".."` can be used as part
+    /// of the list to indicate that the configured values should be appended to the default
+    /// configuration of Clippy. By default, any configuration will replace the default value.
+    #[lints(any_props_bag)]
+    any_props_bag_names: Vec<String> = DEFAULT_ANY_PROPS_BAG_NAMES.iter().map(ToString::to_string).collect(),
     /// Suppress checking of the passed type names in all types of operations.
     ///
     /// If a specific operation is desired, consider using `arithmetic_side_effects_allowed_binary` or `arithmetic_side_effects_allowed_unary` instead.
@@ -511,6 +518,7 @@ define_Conf! {
         enum_variant_names,
         large_types_passed_by_value,
         linkedlist,
+        needless_mut_any,
         needless_pass_by_ref_mut,
         option_option,
         owned_cow,
@@ -566,6 +574,14 @@ define_Conf! {
     /// Whether to also run the listed lints on private items.
     #[lints(missing_errors_doc, missing_panics_doc, missing_safety_doc, unnecessary_safety_doc)]
     check_private_items: bool = false,
+    /// Whether `coerce_container_to_any` should suggest dereferencing the container. If disabled,
+    /// the lint is emitted with a help message instead, which is not applied by `--fix`.
+    #[lints(coerce_container_to_any)]
+    coerce_any_emit_suggestion: bool = true,
+    /// Which containers of a `dyn Any` `coerce_container_to_any` lints borrows of, among `"box"`, `"rc"`
+    /// and `"arc"`. Other types that dereference to a `dyn Any` are always linted.
+    #[lints(coerce_container_to_any)]
+    coerce_any_pointer_kinds: Vec<AnyPointerKind> = vec![AnyPointerKind::Box, AnyPointerKind::Rc, AnyPointerKind::Arc],
     /// The maximum cognitive complexity a function can have
     #[lints(cognitive_complexity)]
     cognitive_complexity_threshold: u64 = 25,
@@ -764,6 +780,7 @@ define_Conf! {
         seek_from_current,
         seek_rewind,
         to_digit_is_some,
+        trait_object_ptr_eq,
         transmute_ptr_to_ref,
         tuple_array_conversions,
         type_repetition_in_bounds,
@@ -916,6 +933,7 @@ fn deserialize(file: &SourceFile) -> TryConf {
         Ok(mut conf) => {
             extend_vec_if_indicator_present(&mut conf.conf.disallowed_names, DEFAULT_DISALLOWED_NAMES);
             extend_vec_if_indicator_present(&mut conf.conf.allowed_prefixes, DEFAULT_ALLOWED_PREFIXES);
+            extend_vec_if_indicator_present(&mut conf.conf.any_props_bag_names, DEFAULT_ANY_PROPS_BAG_NAMES);
             extend_vec_if_indicator_present(
                 &mut conf.conf.allow_renamed_params_for,
                 DEFAULT_ALLOWED_TRAITS_WITH_RENAMED_PARAMS,
@@ -693,6 +693,14 @@ unimplemented_serialize! {
     MacroMatcher,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AnyPointerKind {
+    Box,
+    Rc,
+    Arc,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub enum PubUnderscoreFieldsBehaviour {
     PubliclyExported,
@@ -0,0 +1,86 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::any_downcast;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{get_parent_expr, sym};
+use core::ops::ControlFlow;
+use rustc_hir::{Body, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for downcasts of a `dyn Any` inside of a `Drop::drop` implementation.
+    ///
+    /// ### Why is this bad?
+    /// Running type-specific cleanup by downcasting a `Box<dyn Any>` field ties the
+    /// destructor to the set of types the field may hold, and is easy to get out of sync
+    /// with it. Unwrapping the result of such a downcast panics inside of `drop`, which
+    /// aborts the process if it happens during unwinding.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// struct Resource {
+    ///     handle: Box<dyn Any>,
+    /// }
+    ///
+    /// impl Drop for Resource {
+    ///     fn drop(&mut self) {
+    ///         let handle = self.handle.downcast_mut::<Vec<u8>>().unwrap();
+    ///         handle.clear();
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// struct Resource {
+    ///     handle: Vec<u8>,
+    /// }
+    ///
+    /// impl Drop for Resource {
+    ///     fn drop(&mut self) {
+    ///         self.handle.clear();
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub ANY_DOWNCAST_IN_DROP,
+    restriction,
+    "downcasting a `dyn Any` inside of `Drop::drop`"
+}
+declare_lint_pass!(AnyDowncastInDrop => [ANY_DOWNCAST_IN_DROP]);
+
+impl<'tcx> LateLintPass<'tcx> for AnyDowncastInDrop {
+    fn check_body(&mut self, cx: &LateContext<'tcx>, body: &Body<'tcx>) {
+        let owner = cx.tcx.hir_body_owner_def_id(body.id()).to_def_id();
+        if let Some(impl_id) = cx.tcx.impl_of_method(owner)
+            && let Some(trait_id) = cx.tcx.trait_id_of_impl(impl_id)
+            && cx.tcx.lang_items().drop_trait() == Some(trait_id)
+        {
+            // Closures inside of `drop` are visited here, as their bodies are not owned by `drop`
+            for_each_expr(cx, body.value, |e| {
+                if let Some((name, ..)) = any_downcast(cx, e)
+                    && name != sym::is
+                    && !e.span.from_expansion()
+                {
+                    span_lint_and_then(
+                        cx,
+                        ANY_DOWNCAST_IN_DROP,
+                        e.span,
+                        "downcasting a `dyn Any` inside of `Drop::drop`",
+                        |diag| {
+                            if let Some(parent) = get_parent_expr(cx, e)
+                                && let ExprKind::MethodCall(path, ..) = parent.kind
+                                && matches!(path.ident.name, sym::unwrap | sym::expect)
+                            {
+                                diag.span_note(parent.span, "this panics if the downcast fails");
+                            }
+                            diag.help("consider storing the value with a concrete type instead");
+                        },
+                    );
+                }
+                ControlFlow::<()>::Continue(())
+            });
+        }
+    }
+}
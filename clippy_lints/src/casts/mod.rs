@@ -21,6 +21,7 @@ mod fn_to_numeric_cast_with_truncation;
 mod manual_dangling_ptr;
 mod ptr_as_ptr;
 mod ptr_cast_constness;
+mod redundant_any_cast;
 mod ref_as_ptr;
 mod unnecessary_cast;
 mod utils;
@@ -813,6 +814,35 @@ declare_clippy_lint! {
     "casting a primitive method pointer to any integer type"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for casts to `Box<dyn Any>` or `&dyn Any` of expressions that already have that
+    /// type.
+    ///
+    /// ### Why is this bad?
+    /// The cast does nothing. In particular, it does not force a container to be coerced to
+    /// the `dyn Any` it holds, which is sometimes the intention behind it.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// fn store(value: Box<dyn Any>) -> Vec<Box<dyn Any>> {
+    ///     vec![value as Box<dyn Any>]
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::any::Any;
+    /// fn store(value: Box<dyn Any>) -> Vec<Box<dyn Any>> {
+    ///     vec![value]
+    /// }
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub REDUNDANT_ANY_CAST,
+    style,
+    "casting a `Box<dyn Any>` or `&dyn Any` to its own type"
+}
+
 pub struct Casts {
     msrv: Msrv,
 }
@@ -851,6 +881,7 @@ impl_lint_pass!(Casts => [
     AS_POINTER_UNDERSCORE,
     MANUAL_DANGLING_PTR,
     CONFUSING_METHOD_TO_NUMERIC_CAST,
+    REDUNDANT_ANY_CAST,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Casts {
@@ -879,6 +910,7 @@ impl<'tcx> LateLintPass<'tcx> for Casts {
             fn_to_numeric_cast::check(cx, expr, cast_from_expr, cast_from, cast_to);
             fn_to_numeric_cast_with_truncation::check(cx, expr, cast_from_expr, cast_from, cast_to);
             zero_ptr::check(cx, expr, cast_from_expr, cast_to_hir);
+            redundant_any_cast::check(cx, expr, cast_from_expr, cast_from, cast_to);
 
             if self.msrv.meets(cx, msrvs::MANUAL_DANGLING_PTR) {
                 manual_dangling_ptr::check(cx, expr, cast_from_expr, cast_to_hir);
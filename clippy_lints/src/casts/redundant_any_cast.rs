@@ -0,0 +1,40 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::is_dyn_any;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, Mutability};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+
+use super::REDUNDANT_ANY_CAST;
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    cast_from_expr: &Expr<'_>,
+    cast_from: Ty<'tcx>,
+    cast_to: Ty<'tcx>,
+) {
+    let pointee = match *cast_to.kind() {
+        // Casting a `&mut` reborrows it, while removing the cast moves it
+        ty::Ref(_, _, Mutability::Mut) => None,
+        ty::Ref(_, pointee, Mutability::Not) => Some(pointee),
+        _ => cast_to.boxed_ty(),
+    };
+    if pointee.is_some_and(|pointee| is_dyn_any(cx.tcx, pointee))
+        && !expr.span.from_expansion()
+        && cx.tcx.erase_regions(cast_from) == cx.tcx.erase_regions(cast_to)
+    {
+        let mut applicability = Applicability::MachineApplicable;
+        let (snippet, _) = snippet_with_context(cx, cast_from_expr.span, expr.span.ctxt(), "..", &mut applicability);
+        span_lint_and_sugg(
+            cx,
+            REDUNDANT_ANY_CAST,
+            expr.span,
+            format!("casting `{cast_from}` to the same type is redundant"),
+            "remove the cast",
+            snippet.into_owned(),
+            applicability,
+        );
+    }
+}
@@ -1,11 +1,16 @@
-use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::source::snippet;
-use clippy_utils::sym;
+use clippy_config::Conf;
+use clippy_config::types::AnyPointerKind;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::get_parent_expr;
+use clippy_utils::sugg::render_deref_suggestion;
+use clippy_utils::ty::{any_downcast, deref_chain_len, dyn_any_auto_traits, is_dyn_any};
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind};
-use rustc_lint::{LateContext, LateLintPass};
-use rustc_middle::ty::{self, ExistentialPredicate, Ty, TyCtxt};
-use rustc_session::declare_lint_pass;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::adjustment::{Adjust, PointerCoercion};
+use rustc_middle::ty::{self, Ty, TypeVisitableExt};
+use rustc_session::impl_lint_pass;
+use rustc_span::{Symbol, sym};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -45,21 +50,45 @@ declare_clippy_lint! {
     suspicious,
     "coercing to `&dyn Any` when dereferencing could produce a `dyn Any` without coercion is usually not intended"
 }
-declare_lint_pass!(CoerceContainerToAny => [COERCE_CONTAINER_TO_ANY]);
+
+pub struct CoerceContainerToAny {
+    emit_suggestion: bool,
+    pointer_kinds: &'static [AnyPointerKind],
+}
+
+impl CoerceContainerToAny {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            emit_suggestion: conf.coerce_any_emit_suggestion,
+            pointer_kinds: &conf.coerce_any_pointer_kinds,
+        }
+    }
+}
+
+impl_lint_pass!(CoerceContainerToAny => [COERCE_CONTAINER_TO_ANY]);
 
 impl<'tcx> LateLintPass<'tcx> for CoerceContainerToAny {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
-        // If this expression has an effective type of `&dyn Any` ...
-        {
-            let coerced_ty = cx.typeck_results().expr_ty_adjusted(e);
+        if e.span.in_external_macro(cx.sess().source_map()) {
+            return;
+        }
 
-            let ty::Ref(_, coerced_ref_ty, _) = *coerced_ty.kind() else {
-                return;
-            };
-            if !is_dyn_any(cx.tcx, coerced_ref_ty) {
-                return;
+        // If this expression is unsized to `&dyn Any` ...
+        // (A method call autoderefs its receiver through the container to reach the methods of
+        // `dyn Any`, which gives it the same effective type without coercing the container.)
+        let Some(auto_traits) = cx.typeck_results().expr_adjustments(e).iter().find_map(|adjustment| {
+            // Only the outermost reference can be unsized, so targets such as `&&dyn Any` are
+            // never the result of coercing a container and are skipped here.
+            if let Adjust::Pointer(PointerCoercion::Unsize) = adjustment.kind
+                && let ty::Ref(_, coerced_ref_ty, _) = *adjustment.target.kind()
+            {
+                dyn_any_auto_traits(cx.tcx, coerced_ref_ty)
+            } else {
+                None
             }
-        }
+        }) else {
+            return;
+        };
 
         let expr_ty = cx.typeck_results().expr_ty(e);
         let ty::Ref(_, expr_ref_ty, _) = *expr_ty.kind() else {
@@ -70,39 +99,87 @@ impl<'tcx> LateLintPass<'tcx> for CoerceContainerToAny {
             return;
         }
         // ... and it also *derefs* to `dyn Any` ...
-        let Some((depth, target)) = clippy_utils::ty::deref_chain(cx, expr_ref_ty).enumerate().last() else {
+        // (The chain follows any `Deref` impl, so a locked `MutexGuard<Box<dyn Any>>` is linted
+        // like the `Box` itself. Raw pointers don't implement `Deref`, so the chain stops at
+        // them. Dereferencing one requires `unsafe`, which we won't suggest.)
+        let Some(depth) = deref_chain_len(cx, expr_ref_ty, |ty| is_dyn_any(cx.tcx, ty)) else {
             return;
         };
-        if !is_dyn_any(cx.tcx, target) {
+        // ... from a kind of container the user wants linted ...
+        if let Some(kind) = pointer_kind(cx, expr_ref_ty.peel_refs())
+            && !self.pointer_kinds.contains(&kind)
+        {
             return;
         }
 
         // ... that's probably not intended.
-        let (span, deref_count) = match e.kind {
-            // If `e` was already an `&` expression, skip `*&` in the suggestion
-            ExprKind::AddrOf(_, _, referent) => (referent.span, depth),
-            _ => (e.span, depth + 1),
+        let target_ty = if auto_traits.is_empty() {
+            "&dyn Any".to_owned()
+        } else {
+            format!("&({auto_traits})")
+        };
+        let downcast = immediate_downcast(cx, e, expr_ref_ty);
+        let msg = if let Some((method, target)) = downcast {
+            let fails = if method == sym::is { "`false`" } else { "`None`" };
+            format!(
+                "`{method}::<{target}>` is called on the `{expr_ref_ty}` itself, not the `dyn Any` it holds, and always returns {fails}"
+            )
+        } else if let ExprKind::AddrOf(_, _, referent) = e.kind
+            && let referent_ty = cx.typeck_results().expr_ty(referent)
+            && !referent_ty.is_ref()
+        {
+            // Borrowing an owned container (`&x`) and passing on a reference to one (`ref_x`) need
+            // a different number of derefs, so say which one it is.
+            format!("coercing a borrow of `{referent_ty}` to `{target_ty}`")
+        } else {
+            format!("coercing the reference `{expr_ty}` to `{target_ty}`")
         };
-        span_lint_and_sugg(
-            cx,
-            COERCE_CONTAINER_TO_ANY,
-            e.span,
-            format!("coercing `{expr_ty}` to `&dyn Any`"),
-            "consider dereferencing",
-            format!("&{}{}", str::repeat("*", deref_count), snippet(cx, span, "x")),
-            Applicability::MaybeIncorrect,
-        );
+        span_lint_and_then(cx, COERCE_CONTAINER_TO_ANY, e.span, msg, |diag| {
+            // The downcast is certainly wrong, so dereferencing to the `dyn Any` is certainly the fix
+            let mut applicability = if downcast.is_some() {
+                Applicability::MachineApplicable
+            } else {
+                Applicability::MaybeIncorrect
+            };
+            let sugg = render_deref_suggestion(cx, e, depth, &mut applicability);
+            if self.emit_suggestion {
+                diag.multipart_suggestion("consider dereferencing", sugg, applicability);
+            } else if let [(_, sugg)] = &sugg[..] {
+                // Without a suggestion, `--fix` and IDEs won't apply the change
+                diag.help(format!("consider dereferencing: `{sugg}`"));
+            }
+        });
     }
 }
 
-fn is_dyn_any(tcx: TyCtxt<'_>, ty: Ty<'_>) -> bool {
-    let ty::Dynamic(traits, ..) = ty.kind() else {
-        return false;
-    };
-    traits.iter().any(|binder| {
-        let ExistentialPredicate::Trait(t) = binder.skip_binder() else {
-            return false;
-        };
-        tcx.is_diagnostic_item(sym::Any, t.def_id)
-    })
+/// If the coerced reference, possibly cast to `&dyn Any`, is the receiver of a downcast to a type
+/// other than the container, returns the name of the downcast method and its target type.
+fn immediate_downcast<'tcx>(
+    cx: &LateContext<'tcx>,
+    e: &'tcx Expr<'_>,
+    container: Ty<'tcx>,
+) -> Option<(Symbol, Ty<'tcx>)> {
+    let mut recv = e;
+    if let Some(parent) = get_parent_expr(cx, e)
+        && let ExprKind::Cast(..) = parent.kind
+    {
+        recv = parent;
+    }
+    let call = get_parent_expr(cx, recv)?;
+    let (method, call_recv, target) = any_downcast(cx, call)?;
+    (call_recv.hir_id == recv.hir_id && !target.has_param() && target != container).then_some((method, target))
+}
+
+/// Returns which of the configurable pointer kinds the type is, if any.
+fn pointer_kind(cx: &LateContext<'_>, ty: Ty<'_>) -> Option<AnyPointerKind> {
+    let adt = ty.ty_adt_def()?;
+    if adt.is_box() {
+        Some(AnyPointerKind::Box)
+    } else {
+        match cx.tcx.get_diagnostic_name(adt.did())? {
+            sym::Rc => Some(AnyPointerKind::Rc),
+            sym::Arc => Some(AnyPointerKind::Arc),
+            _ => None,
+        }
+    }
 }
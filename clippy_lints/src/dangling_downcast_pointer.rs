@@ -0,0 +1,111 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::any_downcast;
+use clippy_utils::{get_parent_expr, is_expr_temporary_value, is_path_diagnostic_item, sym};
+use rustc_hir::{Expr, ExprKind, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow};
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for raw pointers to the result of `downcast_ref` or `downcast_mut` on a temporary
+    /// `Box<dyn Any>`, e.g. one returned by a function, which outlive the statement.
+    ///
+    /// ### Why is this bad?
+    /// The temporary `Box`, and the value it holds, is dropped at the end of the statement.
+    /// The borrow checker rejects returning the downcast reference itself, but not a raw pointer
+    /// made from it, which is then dangling.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// # fn make() -> Box<dyn Any> { Box::new(1u32) }
+    /// let ptr = make().downcast_ref::<u32>().unwrap() as *const u32;
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::any::Any;
+    /// # fn make() -> Box<dyn Any> { Box::new(1u32) }
+    /// let value = make();
+    /// let ptr = value.downcast_ref::<u32>().unwrap() as *const u32;
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub DANGLING_DOWNCAST_POINTER,
+    suspicious,
+    "a raw pointer to the downcast contents of a temporary `Box<dyn Any>`"
+}
+declare_lint_pass!(DanglingDowncastPointer => [DANGLING_DOWNCAST_POINTER]);
+
+impl<'tcx> LateLintPass<'tcx> for DanglingDowncastPointer {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let Some((method @ (sym::downcast_ref | sym::downcast_mut), recv, _)) = any_downcast(cx, expr)
+            && !expr.span.from_expansion()
+            && let recv_ty = cx.typeck_results().expr_ty(recv)
+            // A temporary reference may point to something that lives longer, and another owner of
+            // a temporary `Rc` or `Arc` may keep the value alive
+            && recv_ty.is_box()
+            && is_expr_temporary_value(cx, recv)
+            && let Some(ptr) = raw_pointer_from(cx, expr)
+            && escapes_statement(cx, ptr)
+        {
+            span_lint_and_then(
+                cx,
+                DANGLING_DOWNCAST_POINTER,
+                ptr.span,
+                format!("this pointer to the result of `{method}` outlives the temporary `{recv_ty}` it points into"),
+                |diag| {
+                    diag.span_note(recv.span, "this temporary is dropped at the end of the statement");
+                    diag.help("bind the `Box` to a local variable which outlives the pointer");
+                },
+            );
+        }
+    }
+}
+
+/// If the result of the downcast, possibly unwrapped, is converted to a raw pointer, returns the
+/// expression of that pointer.
+fn raw_pointer_from<'tcx>(cx: &LateContext<'tcx>, downcast: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    let mut expr = downcast;
+    while let Some(parent) = get_parent_expr(cx, expr)
+        && let ExprKind::MethodCall(method, recv, _, _) = parent.kind
+        && recv.hir_id == expr.hir_id
+        && matches!(method.ident.name, sym::unwrap | sym::expect | sym::unwrap_unchecked)
+    {
+        expr = parent;
+    }
+    if expr.hir_id == downcast.hir_id {
+        // The `Option` itself isn't a pointer into the temporary
+        return None;
+    }
+
+    if let Some(parent) = get_parent_expr(cx, expr)
+        && match parent.kind {
+            ExprKind::Cast(..) => cx.typeck_results().expr_ty(parent).is_raw_ptr(),
+            ExprKind::Call(func, [_]) => is_path_diagnostic_item(cx, func, sym::ptr_from_ref),
+            _ => false,
+        }
+    {
+        Some(parent)
+    } else {
+        // A coercion of the reference to a pointer
+        cx.typeck_results()
+            .expr_adjustments(expr)
+            .iter()
+            .any(|adjustment| matches!(adjustment.kind, Adjust::Borrow(AutoBorrow::RawPtr(_))))
+            .then_some(expr)
+    }
+}
+
+/// Checks if the value of the expression is stored, assigned or returned, so it's used after
+/// the end of the statement.
+fn escapes_statement(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    match cx.tcx.parent_hir_node(expr.hir_id) {
+        Node::LetStmt(_) => true,
+        Node::Expr(parent) => match parent.kind {
+            ExprKind::Assign(_, rhs, _) => rhs.hir_id == expr.hir_id,
+            ExprKind::Ret(_) => true,
+            _ => false,
+        },
+        _ => cx.tcx.hir_get_fn_id_for_return_block(expr.hir_id).is_some(),
+    }
+}
@@ -0,0 +1,64 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::{any_downcast, contains_dyn_trait};
+use rustc_hir::Expr;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::TypeVisitableExt;
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for downcasts of a `dyn Any` to a type holding a trait object, such as
+    /// `Box<dyn Debug>`.
+    ///
+    /// ### Why is this bad?
+    /// A downcast only succeeds if the value has exactly the target type. Downcasting to
+    /// `Box<dyn Debug>` does not match values of types implementing `Debug`, or even a
+    /// `Box<T>` of such a type, only values that were created as a `Box<dyn Debug>`
+    /// themselves. This is rarely what was intended.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// # use std::fmt::Debug;
+    /// fn print(value: &dyn Any) {
+    ///     if let Some(value) = value.downcast_ref::<Box<dyn Debug>>() {
+    ///         println!("{value:?}");
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::fmt::Debug;
+    /// fn print(value: &dyn Debug) {
+    ///     println!("{value:?}");
+    /// }
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub DOWNCAST_TO_TRAIT_OBJECT,
+    suspicious,
+    "downcasting a `dyn Any` to a type holding a trait object"
+}
+declare_lint_pass!(DowncastToTraitObject => [DOWNCAST_TO_TRAIT_OBJECT]);
+
+impl<'tcx> LateLintPass<'tcx> for DowncastToTraitObject {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) {
+        if let Some((_, _, target)) = any_downcast(cx, e)
+            && !e.span.from_expansion()
+            && !target.has_param()
+            && contains_dyn_trait(target)
+        {
+            span_lint_and_then(
+                cx,
+                DOWNCAST_TO_TRAIT_OBJECT,
+                e.span,
+                format!("downcasting to `{target}`, which holds a trait object"),
+                |diag| {
+                    diag.note(format!(
+                        "this only matches values created as a `{target}`, not values coercible to it"
+                    ));
+                    diag.help("downcast to the concrete type the value was created from instead");
+                },
+            );
+        }
+    }
+}
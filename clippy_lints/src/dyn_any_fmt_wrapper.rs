@@ -0,0 +1,121 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::sym;
+use clippy_utils::ty::is_dyn_any;
+use clippy_utils::visitors::for_each_expr;
+use core::ops::ControlFlow;
+use rustc_hir::{Expr, ExprKind, Impl, ImplItemKind, Item, ItemKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty, TyCtxt, TypeckResults};
+use rustc_session::declare_lint_pass;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Display` and `Debug` implementations of types wrapping a `dyn Any` whose
+    /// `fmt` method tries several `downcast_ref` calls in turn.
+    ///
+    /// ### Why is this bad?
+    /// The set of supported types is only encoded in the downcast cascade. A value of any
+    /// other type silently falls through, and adding a new type requires finding every such
+    /// cascade. An enum of the supported types is checked by the compiler instead.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// # use std::fmt;
+    /// struct AnyDisplay<'a>(&'a dyn Any);
+    ///
+    /// impl fmt::Display for AnyDisplay<'_> {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         if let Some(x) = self.0.downcast_ref::<u32>() {
+    ///             write!(f, "{x}")
+    ///         } else if let Some(x) = self.0.downcast_ref::<String>() {
+    ///             write!(f, "{x}")
+    ///         } else {
+    ///             write!(f, "<unknown>")
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::fmt;
+    /// enum Value {
+    ///     U32(u32),
+    ///     String(String),
+    /// }
+    ///
+    /// impl fmt::Display for Value {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         match self {
+    ///             Value::U32(x) => write!(f, "{x}"),
+    ///             Value::String(x) => write!(f, "{x}"),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub DYN_ANY_FMT_WRAPPER,
+    pedantic,
+    "formatting a wrapped `dyn Any` by trying several downcasts"
+}
+declare_lint_pass!(DynAnyFmtWrapper => [DYN_ANY_FMT_WRAPPER]);
+
+impl<'tcx> LateLintPass<'tcx> for DynAnyFmtWrapper {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if let ItemKind::Impl(Impl {
+            of_trait: Some(trait_ref),
+            self_ty,
+            items,
+            ..
+        }) = item.kind
+            && let Some(trait_did) = trait_ref.trait_def_id()
+            && let Some(trait_name @ (sym::Debug | sym::Display)) = cx.tcx.get_diagnostic_name(trait_did)
+            && !item.span.from_expansion()
+            && let ty::Adt(adt, args) = cx.tcx.type_of(item.owner_id).instantiate_identity().kind()
+            && adt
+                .all_fields()
+                .any(|field| contains_dyn_any(cx.tcx, field.ty(cx.tcx, args)))
+            && let Some(fmt_item) = items.iter().find(|i| i.ident.name == sym::fmt)
+            && let ImplItemKind::Fn(_, body_id) = cx.tcx.hir_impl_item(fmt_item.id).kind
+            // NB: can't call cx.typeck_results() as we are not in a body
+            && let typeck_results = cx.tcx.typeck_body(body_id)
+            && let downcasts = dyn_any_downcasts(cx, typeck_results, cx.tcx.hir_body(body_id).value)
+            && downcasts.len() >= 2
+        {
+            span_lint_and_then(
+                cx,
+                DYN_ANY_FMT_WRAPPER,
+                self_ty.span,
+                format!("`{trait_name}` implementation downcasts a wrapped `dyn Any`"),
+                |diag| {
+                    diag.span_note(downcasts, "the supported types are only known from these downcasts");
+                    diag.help("consider using an enum of the supported types instead");
+                },
+            );
+        }
+    }
+}
+
+/// Collects the spans of all `downcast_ref` calls on a `dyn Any` in the given expression.
+fn dyn_any_downcasts<'tcx>(
+    cx: &LateContext<'tcx>,
+    typeck_results: &TypeckResults<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+) -> Vec<Span> {
+    let mut downcasts = Vec::new();
+    for_each_expr(cx, expr, |e| {
+        if let ExprKind::MethodCall(path, recv, [], _) = e.kind
+            && path.ident.name == sym::downcast_ref
+            && is_dyn_any(cx.tcx, typeck_results.expr_ty_adjusted(recv).peel_refs())
+        {
+            downcasts.push(e.span);
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    downcasts
+}
+
+fn contains_dyn_any<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> bool {
+    ty.walk().any(|arg| arg.as_type().is_some_and(|ty| is_dyn_any(tcx, ty)))
+}
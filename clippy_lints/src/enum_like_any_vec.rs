@@ -0,0 +1,205 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::ty::{any_downcast, is_dyn_any, is_type_diagnostic_item};
+use clippy_utils::visitors::{for_each_expr, for_each_expr_without_closures};
+use clippy_utils::{get_parent_expr, is_path_diagnostic_item, path_to_local, path_to_local_id, sym};
+use core::ops::ControlFlow;
+use rustc_hir::{Expr, ExprKind, HirId, LetStmt, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty, TypeVisitableExt};
+use rustc_session::declare_lint_pass;
+use rustc_span::DesugaringKind;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for local `Vec<Box<dyn Any>>`s which are only filled with values of a few types
+    /// known in the function, and only read by downcasting to those types.
+    ///
+    /// ### Why is this bad?
+    /// An enum with a variant for each type can be matched on exhaustively, so no downcast can
+    /// fail or be forgotten when a type is added. It also avoids boxing every value.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// let mut values: Vec<Box<dyn Any>> = Vec::new();
+    /// values.push(Box::new(1u32));
+    /// values.push(Box::new(String::from("one")));
+    /// for value in &values {
+    ///     if let Some(n) = value.downcast_ref::<u32>() {
+    ///         println!("{n}");
+    ///     } else if let Some(s) = value.downcast_ref::<String>() {
+    ///         println!("{s}");
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// enum Value {
+    ///     Number(u32),
+    ///     Text(String),
+    /// }
+    ///
+    /// let values = vec![Value::Number(1), Value::Text(String::from("one"))];
+    /// for value in &values {
+    ///     match value {
+    ///         Value::Number(n) => println!("{n}"),
+    ///         Value::Text(s) => println!("{s}"),
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub ENUM_LIKE_ANY_VEC,
+    pedantic,
+    "a `Vec<Box<dyn Any>>` which only holds values of a few known types"
+}
+declare_lint_pass!(EnumLikeAnyVec => [ENUM_LIKE_ANY_VEC]);
+
+/// The largest number of types we'd suggest an enum for
+const MAX_TYPES: usize = 6;
+
+impl<'tcx> LateLintPass<'tcx> for EnumLikeAnyVec {
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx LetStmt<'tcx>) {
+        if let PatKind::Binding(_, binding_id, ident, None) = local.pat.kind
+            && !local.span.from_expansion()
+            && let vec_ty = cx.typeck_results().pat_ty(local.pat)
+            && is_type_diagnostic_item(cx, vec_ty, sym::Vec)
+            && let ty::Adt(_, args) = *vec_ty.kind()
+            && args.type_at(0).boxed_ty().is_some_and(|ty| is_dyn_any(cx.tcx, ty))
+            && let Some(body_id) = cx.enclosing_body
+            && let body = cx.tcx.hir_body(body_id)
+            && let Some(pushed) = pushed_types(cx, body.value, binding_id)
+            && (2..=MAX_TYPES).contains(&pushed.len())
+            && let Some(checked) = downcast_types(cx, body.value, binding_id)
+            && !checked.is_empty()
+            && checked.iter().all(|ty| pushed.contains(ty))
+        {
+            let types = pushed.iter().map(|ty| format!("`{ty}`")).collect::<Vec<_>>().join(", ");
+            span_lint_and_help(
+                cx,
+                ENUM_LIKE_ANY_VEC,
+                local.pat.span,
+                format!("`{ident}` only holds values of {} known types", pushed.len()),
+                None,
+                format!("consider storing an enum with a variant for each of {types} instead"),
+            );
+        }
+    }
+}
+
+/// Returns the types of the values pushed to the `Vec`, in the order they're first pushed.
+/// Returns `None` if the `Vec` is used in any way other than pushing boxed values to it and
+/// reading from it, as other code could then store other types in it.
+fn pushed_types<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Expr<'tcx>, binding_id: HirId) -> Option<Vec<Ty<'tcx>>> {
+    let mut types = Vec::new();
+    for_each_expr(cx, body, |e| {
+        if !path_to_local_id(e, binding_id) {
+            return ControlFlow::Continue(());
+        }
+        let Some(mut parent) = get_parent_expr(cx, e) else {
+            return ControlFlow::Break(());
+        };
+        match parent.kind {
+            ExprKind::MethodCall(method, recv, args, _) if recv.hir_id == e.hir_id => match (method.ident.name, args) {
+                (sym::push, [arg]) => {
+                    if let ExprKind::Call(func, [value]) = arg.kind
+                        && is_path_diagnostic_item(cx, func, sym::box_new)
+                        && let ty = cx.typeck_results().expr_ty(value)
+                        && !ty.has_param()
+                    {
+                        if !types.contains(&ty) {
+                            types.push(ty);
+                        }
+                        return ControlFlow::Continue(());
+                    }
+                    return ControlFlow::Break(());
+                },
+                (sym::iter | sym::get | sym::first | sym::last | sym::len | sym::is_empty, _) => {
+                    return ControlFlow::Continue(());
+                },
+                _ => return ControlFlow::Break(()),
+            },
+            ExprKind::Index(indexed, ..) if indexed.hir_id == e.hir_id => return ControlFlow::Continue(()),
+            ExprKind::AddrOf(..) => {
+                let Some(grandparent) = get_parent_expr(cx, parent) else {
+                    return ControlFlow::Break(());
+                };
+                parent = grandparent;
+            },
+            _ => {},
+        }
+        // `for value in &values`
+        if matches!(parent.kind, ExprKind::Call(..)) && parent.span.is_desugaring(DesugaringKind::ForLoop) {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    })
+    .is_none()
+    .then_some(types)
+}
+
+/// Returns the types the values read from the `Vec` are downcast to. Returns `None` if one is
+/// downcast to a generic type.
+fn downcast_types<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Expr<'tcx>, binding_id: HirId) -> Option<Vec<Ty<'tcx>>> {
+    // The `Vec` and the locals bound to values read from it. Expressions are visited before the
+    // ones they contain, so a local is known before it's used.
+    let mut locals = vec![binding_id];
+    let mut types = Vec::new();
+    for_each_expr(cx, body, |e| {
+        match e.kind {
+            // `for value in &values` is desugared to matches on the iterator and its items
+            ExprKind::Match(scrutinee, arms, _) if mentions_any(scrutinee, &locals) => {
+                for arm in arms {
+                    arm.pat.each_binding(|_, id, _, _| locals.push(id));
+                }
+            },
+            ExprKind::Let(let_expr) if mentions_any(let_expr.init, &locals) => {
+                let_expr.pat.each_binding(|_, id, _, _| locals.push(id));
+            },
+            ExprKind::Block(block, _) => {
+                for stmt in block.stmts {
+                    if let StmtKind::Let(local) = stmt.kind
+                        && local.init.is_some_and(|init| mentions_any(init, &locals))
+                    {
+                        local.pat.each_binding(|_, id, _, _| locals.push(id));
+                    }
+                }
+            },
+            // `values.iter().any(|value| ..)`
+            ExprKind::MethodCall(_, recv, args, _) if mentions_any(recv, &locals) => {
+                for arg in args {
+                    if let ExprKind::Closure(closure) = arg.kind {
+                        for param in cx.tcx.hir_body(closure.body).params {
+                            param.pat.each_binding(|_, id, _, _| locals.push(id));
+                        }
+                    }
+                }
+            },
+            _ => {},
+        }
+        if let Some((_, recv, target)) = any_downcast(cx, e)
+            && mentions_any(recv, &locals)
+        {
+            if target.has_param() {
+                return ControlFlow::Break(());
+            }
+            if !types.contains(&target) {
+                types.push(target);
+            }
+        }
+        ControlFlow::Continue(())
+    })
+    .is_none()
+    .then_some(types)
+}
+
+fn mentions_any(expr: &Expr<'_>, locals: &[HirId]) -> bool {
+    for_each_expr_without_closures(expr, |e| {
+        if path_to_local(e).is_some_and(|id| locals.contains(&id)) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
@@ -0,0 +1,52 @@
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, FnDecl, HirId, PatKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::def_id::LocalDefId;
+
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::is_trait_impl_item;
+use clippy_utils::ty::is_dyn_any;
+
+use super::ANY_PROPS_BAG;
+
+pub(super) fn check_fn<'tcx>(
+    cx: &LateContext<'tcx>,
+    kind: FnKind<'tcx>,
+    decl: &'tcx FnDecl<'tcx>,
+    body: &'tcx Body<'tcx>,
+    hir_id: HirId,
+    def_id: LocalDefId,
+    names: &FxHashSet<&'static str>,
+) {
+    if matches!(kind, FnKind::Closure)
+        || !cx.effective_visibilities.is_exported(def_id)
+        // The signature is dictated by the trait
+        || is_trait_impl_item(cx, hir_id)
+        || body.value.span.from_expansion()
+    {
+        return;
+    }
+
+    let sig = cx.tcx.fn_sig(def_id).instantiate_identity().skip_binder();
+    for ((hir_ty, &param_ty), param) in decl.inputs.iter().zip(sig.inputs()).zip(body.params) {
+        let is_any_param = match *param_ty.kind() {
+            ty::Ref(_, inner, _) => is_dyn_any(cx.tcx, inner),
+            _ => param_ty.boxed_ty().is_some_and(|inner| is_dyn_any(cx.tcx, inner)),
+        };
+        let name_matches = names.is_empty()
+            || matches!(param.pat.kind, PatKind::Binding(_, _, ident, _)
+                if names.contains(ident.as_str().trim_start_matches('_')));
+        if is_any_param && name_matches {
+            span_lint_and_help(
+                cx,
+                ANY_PROPS_BAG,
+                hir_ty.span,
+                "exported function takes a `dyn Any` as a catch-all parameter",
+                None,
+                "consider using a dedicated type, an enum or a generic parameter instead",
+            );
+        }
+    }
+}
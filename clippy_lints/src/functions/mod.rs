@@ -1,3 +1,4 @@
+mod any_props_bag;
 mod impl_trait_in_params;
 mod misnamed_getters;
 mod must_use;
@@ -11,6 +12,7 @@ mod too_many_lines;
 use clippy_config::Conf;
 use clippy_utils::msrvs::Msrv;
 use clippy_utils::paths::{PathNS, lookup_path_str};
+use rustc_data_structures::fx::FxHashSet;
 use rustc_hir as hir;
 use rustc_hir::intravisit;
 use rustc_lint::{LateContext, LateLintPass};
@@ -448,6 +450,79 @@ declare_clippy_lint! {
     "function signature uses `&Option<T>` instead of `Option<&T>`"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for functions that return a `Result` with an `Err` type of
+    /// `Box<dyn Any>`.
+    ///
+    /// ### Why is this bad?
+    /// `Box<dyn Any>` does not implement `Error`, so it cannot be displayed,
+    /// has no source chain, and does not convert into other error types with
+    /// the `?` operator. Callers have to guess which types to downcast to in
+    /// order to find out what went wrong.
+    ///
+    /// ### Known problems
+    /// Panic payloads, as returned by `std::thread::JoinHandle::join` and
+    /// `std::panic::catch_unwind`, are `Box<dyn Any + Send>`. Functions that
+    /// intentionally pass such a payload along will be linted as well.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// fn parse(input: &str) -> Result<u32, Box<dyn Any>> {
+    ///     input.parse::<u32>().map_err(|e| Box::new(e) as Box<dyn Any>)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::error::Error;
+    /// fn parse(input: &str) -> Result<u32, Box<dyn Error>> {
+    ///     Ok(input.parse::<u32>()?)
+    /// }
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub ANY_IN_ERROR_POSITION,
+    pedantic,
+    "function returning `Result` with an `Err` type of `Box<dyn Any>`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for exported functions taking a `Box<dyn Any>` or `&dyn Any` parameter with a
+    /// name suggesting a catch-all value, such as `extra` or `props`.
+    ///
+    /// ### Why is this bad?
+    /// Callers can pass anything, and nothing tells them which types the function actually
+    /// handles. Mistakes only show up as failed downcasts at runtime. A dedicated struct, an
+    /// enum or a generic parameter documents the accepted values and is checked by the compiler.
+    ///
+    /// ### Configuration
+    /// The parameter names to lint can be set with `any-props-bag-names`. If the list is
+    /// empty, all `Box<dyn Any>` and `&dyn Any` parameters of exported functions are linted.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// pub fn render(name: &str, props: &dyn Any) {
+    ///     // ..
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// pub struct RenderProps {
+    ///     pub width: u32,
+    /// }
+    ///
+    /// pub fn render(name: &str, props: &RenderProps) {
+    ///     // ..
+    /// }
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub ANY_PROPS_BAG,
+    pedantic,
+    "exported function taking a `dyn Any` as a catch-all parameter"
+}
+
 pub struct Functions {
     too_many_arguments_threshold: u64,
     too_many_lines_threshold: u64,
@@ -457,6 +532,7 @@ pub struct Functions {
     /// function params renaming.
     trait_ids: DefIdSet,
     msrv: Msrv,
+    any_props_bag_names: FxHashSet<&'static str>,
 }
 
 impl Functions {
@@ -472,6 +548,7 @@ impl Functions {
                 .flat_map(|p| lookup_path_str(tcx, PathNS::Type, p))
                 .collect(),
             msrv: conf.msrv,
+            any_props_bag_names: conf.any_props_bag_names.iter().map(|s| &**s).collect(),
         }
     }
 }
@@ -489,6 +566,8 @@ impl_lint_pass!(Functions => [
     IMPL_TRAIT_IN_PARAMS,
     RENAMED_FUNCTION_PARAMS,
     REF_OPTION,
+    ANY_IN_ERROR_POSITION,
+    ANY_PROPS_BAG,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Functions {
@@ -517,6 +596,7 @@ impl<'tcx> LateLintPass<'tcx> for Functions {
             body,
             self.avoid_breaking_exported_api,
         );
+        any_props_bag::check_fn(cx, kind, decl, body, hir_id, def_id, &self.any_props_bag_names);
     }
 
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
@@ -6,10 +6,10 @@ use rustc_middle::ty::{self, Ty};
 use rustc_span::{Span, sym};
 
 use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
-use clippy_utils::ty::{AdtVariantInfo, approx_ty_size, is_type_diagnostic_item};
+use clippy_utils::ty::{AdtVariantInfo, approx_ty_size, is_dyn_any, is_type_diagnostic_item};
 use clippy_utils::{is_no_std_crate, trait_ref_of_method};
 
-use super::{RESULT_LARGE_ERR, RESULT_UNIT_ERR};
+use super::{ANY_IN_ERROR_POSITION, RESULT_LARGE_ERR, RESULT_UNIT_ERR};
 
 /// The type of the `Err`-variant in a `std::result::Result` returned by the
 /// given `FnDecl`
@@ -43,6 +43,7 @@ pub(super) fn check_item<'tcx>(cx: &LateContext<'tcx>, item: &hir::Item<'tcx>, l
             check_result_unit_err(cx, err_ty, fn_header_span, msrv);
         }
         check_result_large_err(cx, err_ty, hir_ty.span, large_err_threshold);
+        check_result_any_err(cx, err_ty, hir_ty.span);
     }
 }
 
@@ -62,6 +63,7 @@ pub(super) fn check_impl_item<'tcx>(
             check_result_unit_err(cx, err_ty, fn_header_span, msrv);
         }
         check_result_large_err(cx, err_ty, hir_ty.span, large_err_threshold);
+        check_result_any_err(cx, err_ty, hir_ty.span);
     }
 }
 
@@ -78,6 +80,7 @@ pub(super) fn check_trait_item<'tcx>(
                 check_result_unit_err(cx, err_ty, fn_header_span, msrv);
             }
             check_result_large_err(cx, err_ty, hir_ty.span, large_err_threshold);
+            check_result_any_err(cx, err_ty, hir_ty.span);
         }
     }
 }
@@ -95,6 +98,19 @@ fn check_result_unit_err(cx: &LateContext<'_>, err_ty: Ty<'_>, fn_header_span: S
     }
 }
 
+fn check_result_any_err(cx: &LateContext<'_>, err_ty: Ty<'_>, hir_ty_span: Span) {
+    if err_ty.boxed_ty().is_some_and(|boxed| is_dyn_any(cx.tcx, boxed)) {
+        span_lint_and_help(
+            cx,
+            ANY_IN_ERROR_POSITION,
+            hir_ty_span,
+            "the `Err`-variant returned from this function is a `Box<dyn Any>`",
+            None,
+            "use `Box<dyn std::error::Error>` or a concrete error type instead",
+        );
+    }
+}
+
 fn check_result_large_err<'tcx>(cx: &LateContext<'tcx>, err_ty: Ty<'tcx>, hir_ty_span: Span, large_err_threshold: u64) {
     if let ty::Adt(adt, subst) = err_ty.kind()
         && let Some(local_def_id) = err_ty
@@ -72,6 +72,7 @@ pub mod deprecated_lints;
 // begin lints modules, do not remove this comment, it's used in `update_lints`
 mod absolute_paths;
 mod almost_complete_range;
+mod any_downcast_in_drop;
 mod approx_const;
 mod arbitrary_source_item_ordering;
 mod arc_with_non_send_sync;
@@ -104,6 +105,7 @@ mod copies;
 mod copy_iterator;
 mod crate_in_macro_def;
 mod create_dir;
+mod dangling_downcast_pointer;
 mod dbg_macro;
 mod default;
 mod default_constructed_unit_structs;
@@ -120,8 +122,10 @@ mod disallowed_script_idents;
 mod disallowed_types;
 mod doc;
 mod double_parens;
+mod downcast_to_trait_object;
 mod drop_forget_ref;
 mod duplicate_mod;
+mod dyn_any_fmt_wrapper;
 mod else_if_without_else;
 mod empty_drop;
 mod empty_enum;
@@ -130,6 +134,7 @@ mod empty_with_brackets;
 mod endian_bytes;
 mod entry;
 mod enum_clike;
+mod enum_like_any_vec;
 mod equatable_if_let;
 mod error_impl_error;
 mod escape;
@@ -231,11 +236,15 @@ mod map_unit_fn;
 mod match_result_ok;
 mod matches;
 mod mem_replace;
+mod mem_replace_discards_any;
 mod methods;
 mod min_ident_chars;
 mod minmax;
 mod misc;
 mod misc_early;
+mod mismatched_any_factory;
+mod mismatched_downcast;
+mod mismatched_downcast_annotation;
 mod mismatching_type_param_order;
 mod missing_assert_message;
 mod missing_asserts_for_indexing;
@@ -256,6 +265,7 @@ mod mut_mut;
 mod mut_reference;
 mod mutable_debug_assertion;
 mod mutex_atomic;
+mod needless_any_clone_bound;
 mod needless_arbitrary_self_type;
 mod needless_bool;
 mod needless_borrowed_ref;
@@ -266,6 +276,7 @@ mod needless_for_each;
 mod needless_if;
 mod needless_late_init;
 mod needless_maybe_sized;
+mod needless_mut_any;
 mod needless_parens_on_range_literals;
 mod needless_pass_by_ref_mut;
 mod needless_pass_by_value;
@@ -291,6 +302,7 @@ mod option_env_unwrap;
 mod option_if_let_else;
 mod panic_in_result_fn;
 mod panic_unimplemented;
+mod panicking_any_clone;
 mod panicking_overflow_checks;
 mod partial_pub_fields;
 mod partialeq_ne_impl;
@@ -311,6 +323,7 @@ mod ranges;
 mod raw_strings;
 mod rc_clone_in_vec_init;
 mod read_zero_byte_vec;
+mod redundant_as_any_coercion;
 mod redundant_async_block;
 mod redundant_clone;
 mod redundant_closure_call;
@@ -347,6 +360,7 @@ mod size_of_in_element_count;
 mod size_of_ref;
 mod slow_vector_initialization;
 mod std_instead_of_core;
+mod string_keyed_any_map;
 mod string_patterns;
 mod strings;
 mod strlen_on_c_strings;
@@ -358,12 +372,17 @@ mod swap_ptr_to_ref;
 mod tabs_in_doc_comments;
 mod temporary_assignment;
 mod tests_outside_test_module;
+mod thread_local_dyn_any;
 mod to_digit_is_some;
 mod to_string_trait_impl;
 mod trailing_empty_array;
 mod trait_bounds;
+mod trait_object_ptr_eq;
 mod transmute;
+mod transmute_to_static_any;
 mod tuple_array_conversions;
+mod type_id_debug_key;
+mod type_name_for_dispatch;
 mod types;
 mod unconditional_recursion;
 mod undocumented_unsafe_blocks;
@@ -947,6 +966,26 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(move |_| Box::new(redundant_test_prefix::RedundantTestPrefix));
     store.register_late_pass(|_| Box::new(cloned_ref_to_slice_refs::ClonedRefToSliceRefs::new(conf)));
     store.register_late_pass(|_| Box::new(infallible_try_from::InfallibleTryFrom));
-    store.register_late_pass(|_| Box::new(coerce_container_to_any::CoerceContainerToAny));
+    store.register_late_pass(move |_| Box::new(coerce_container_to_any::CoerceContainerToAny::new(conf)));
+    store.register_late_pass(|_| Box::new(thread_local_dyn_any::ThreadLocalDynAny));
+    store.register_late_pass(|_| Box::new(dyn_any_fmt_wrapper::DynAnyFmtWrapper));
+    store.register_late_pass(|_| Box::new(mismatched_downcast::MismatchedDowncast));
+    store.register_late_pass(|_| Box::new(any_downcast_in_drop::AnyDowncastInDrop));
+    store.register_late_pass(|_| Box::new(downcast_to_trait_object::DowncastToTraitObject));
+    store.register_late_pass(|_| Box::new(mismatched_downcast_annotation::MismatchedDowncastAnnotation));
+    store.register_late_pass(move |_| Box::new(trait_object_ptr_eq::TraitObjectPtrEq::new(conf)));
+    store.register_late_pass(|_| Box::new(mem_replace_discards_any::MemReplaceDiscardsAny));
+    store.register_late_pass(|_| Box::new(needless_any_clone_bound::NeedlessAnyCloneBound));
+    store.register_late_pass(move |_| Box::new(needless_mut_any::NeedlessMutAny::new(conf)));
+    store.register_late_pass(|_| Box::new(type_name_for_dispatch::TypeNameForDispatch));
+    store.register_late_pass(|_| Box::new(panicking_any_clone::PanickingAnyClone));
+    store.register_late_pass(|_| Box::new(transmute_to_static_any::TransmuteToStaticAny));
+    store.register_late_pass(|_| Box::new(enum_like_any_vec::EnumLikeAnyVec));
+    let format_args = format_args_storage.clone();
+    store.register_late_pass(move |_| Box::new(type_id_debug_key::TypeIdDebugKey::new(format_args.clone())));
+    store.register_late_pass(|_| Box::new(dangling_downcast_pointer::DanglingDowncastPointer));
+    store.register_late_pass(|_| Box::new(string_keyed_any_map::StringKeyedAnyMap));
+    store.register_late_pass(|_| Box::new(redundant_as_any_coercion::RedundantAsAnyCoercion));
+    store.register_late_pass(|_| Box::new(mismatched_any_factory::MismatchedAnyFactory));
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
@@ -0,0 +1,100 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::ty::is_dyn_any;
+use clippy_utils::{is_expr_used_or_unified, paths};
+use rustc_hir::{Expr, ExprKind, LetStmt, Mutability, Node, Pat, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `mem::replace` and `mem::take` on a field holding a `dyn Any`,
+    /// such as a `Box<dyn Any>`, whose result is discarded.
+    ///
+    /// ### Why is this bad?
+    /// The old value is dropped without ever being downcast, so whatever it held is lost.
+    /// If it is replaced to get at the old value, the code downcasting it is probably
+    /// missing. If the old value is not needed, assigning to the field is clearer.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// struct State {
+    ///     value: Box<dyn Any>,
+    /// }
+    ///
+    /// impl State {
+    ///     fn reset(&mut self) {
+    ///         let _ = std::mem::replace(&mut self.value, Box::new(()));
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::any::Any;
+    /// struct State {
+    ///     value: Box<dyn Any>,
+    /// }
+    ///
+    /// impl State {
+    ///     fn reset(&mut self) {
+    ///         self.value = Box::new(());
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub MEM_REPLACE_DISCARDS_ANY,
+    pedantic,
+    "discarding the `dyn Any` value replaced with `mem::replace` or `mem::take`"
+}
+declare_lint_pass!(MemReplaceDiscardsAny => [MEM_REPLACE_DISCARDS_ANY]);
+
+impl<'tcx> LateLintPass<'tcx> for MemReplaceDiscardsAny {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Call(func, [dest, ..]) = expr.kind
+            && !expr.span.from_expansion()
+            && let ExprKind::Path(ref func_qpath) = func.kind
+            && let Some(def_id) = cx.qpath_res(func_qpath, func.hir_id).opt_def_id()
+            && let Some(func_name) = if cx.tcx.is_diagnostic_item(sym::mem_replace, def_id) {
+                Some("replace")
+            } else if paths::MEM_TAKE.matches(cx, def_id) {
+                Some("take")
+            } else {
+                None
+            }
+            && let ExprKind::AddrOf(_, Mutability::Mut, place) = dest.kind
+            && let ExprKind::Field(..) = place.kind
+            && cx
+                .typeck_results()
+                .expr_ty(place)
+                .walk()
+                .any(|arg| arg.as_type().is_some_and(|ty| is_dyn_any(cx.tcx, ty)))
+            && is_discarded(cx, expr)
+        {
+            span_lint_and_help(
+                cx,
+                MEM_REPLACE_DISCARDS_ANY,
+                expr.span,
+                format!("the `dyn Any` value replaced with `mem::{func_name}` is discarded"),
+                None,
+                "assign to the field directly if the old value is not needed, or downcast it to handle it",
+            );
+        }
+    }
+}
+
+/// Checks if the value of the expression is dropped immediately, either as a statement or by
+/// binding it to `_`.
+fn is_discarded(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    !is_expr_used_or_unified(cx.tcx, expr)
+        || matches!(
+            cx.tcx.parent_hir_node(expr.hir_id),
+            Node::LetStmt(LetStmt {
+                pat: Pat {
+                    kind: PatKind::Wild,
+                    ..
+                },
+                ..
+            })
+        )
+}
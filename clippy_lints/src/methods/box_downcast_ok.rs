@@ -0,0 +1,24 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::sym;
+use clippy_utils::ty::any_downcast;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+
+use super::BOX_DOWNCAST_OK;
+
+/// lint use of `downcast().ok()` for `Box<dyn Any>`
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &hir::Expr<'_>, recv: &'tcx hir::Expr<'tcx>) {
+    if let Some((sym::downcast, downcast_recv, target)) = any_downcast(cx, recv)
+        && let recv_ty = cx.typeck_results().expr_ty_adjusted(downcast_recv)
+        && recv_ty.is_box()
+    {
+        span_lint_and_help(
+            cx,
+            BOX_DOWNCAST_OK,
+            expr.span,
+            format!("called `ok()` on the result of downcasting a box to `{target}`"),
+            None,
+            "if the downcast fails, the `Err` holds the original box; consider handling it instead of dropping it",
+        );
+    }
+}
@@ -1,4 +1,5 @@
 mod bind_instead_of_map;
+mod box_downcast_ok;
 mod bytecount;
 mod bytes_count_to_len;
 mod bytes_nth;
@@ -4565,6 +4566,35 @@ declare_clippy_lint! {
     "hardcoded localhost IP address"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for usage of `.downcast::<T>().ok()` on a `Box<dyn Any>`.
+    ///
+    /// ### Why is this bad?
+    /// If the downcast fails, the `Err` holds the original box, which is the only way to get the
+    /// value back. Converting the result to an `Option` drops it, so it can't be downcast to
+    /// another type or handed back to the caller.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// fn take<T: Any>(value: Box<dyn Any>) -> Option<Box<T>> {
+    ///     value.downcast::<T>().ok()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::any::Any;
+    /// fn take<T: Any>(value: Box<dyn Any>) -> Result<Box<T>, Box<dyn Any>> {
+    ///     value.downcast::<T>()
+    /// }
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub BOX_DOWNCAST_OK,
+    pedantic,
+    "discarding the box returned by a failed `Box<dyn Any>` downcast"
+}
+
 #[expect(clippy::struct_excessive_bools)]
 pub struct Methods {
     avoid_breaking_exported_api: bool,
@@ -4744,6 +4774,7 @@ impl_lint_pass!(Methods => [
     IO_OTHER_ERROR,
     SWAP_WITH_TEMPORARY,
     IP_CONSTANT,
+    BOX_DOWNCAST_OK,
 ]);
 
 /// Extracts a method call name, args, and `Span` of the method name.
@@ -5327,6 +5358,11 @@ impl Methods {
                     },
                     _ => iter_nth_zero::check(cx, expr, recv, n_arg),
                 },
+                (sym::ok, []) => {
+                    if let Some((sym::downcast, _, [], _, _)) = method_call(recv) {
+                        box_downcast_ok::check(cx, expr, recv);
+                    }
+                },
                 (sym::ok_or_else, [arg]) => {
                     unnecessary_lazy_eval::check(cx, expr, recv, arg, "ok_or");
                 },
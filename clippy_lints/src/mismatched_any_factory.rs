@@ -0,0 +1,179 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::{is_dyn_any, is_type_diagnostic_item};
+use clippy_utils::visitors::{for_each_expr_without_closures, is_local_used};
+use clippy_utils::{eq_expr_value, expr_or_init, is_path_diagnostic_item, path_to_local, sym};
+use core::ops::ControlFlow;
+use rustc_hir::{Block, Expr, ExprKind, HirId, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty, TypeVisitableExt};
+use rustc_session::declare_lint_pass;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for factory closures returning `Box<dyn Any>` which replace another factory
+    /// under the same key of a map, or at the same index of a `Vec`, in the same block, when
+    /// the two factories return values of different concrete types. Factories are only
+    /// compared if the container and the key aren't used by the statements between them.
+    ///
+    /// ### Why is this bad?
+    /// Code looking up a factory by its key or index usually downcasts the value it returns to
+    /// one type. Replacing the factory with one returning another type makes that downcast
+    /// fail at runtime.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// # use std::collections::HashMap;
+    /// let mut factories: HashMap<&str, Box<dyn Fn() -> Box<dyn Any>>> = HashMap::new();
+    /// factories.insert("port", Box::new(|| Box::new(8080u16)));
+    /// factories.insert("port", Box::new(|| Box::new(String::from("8080"))));
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::any::Any;
+    /// # use std::collections::HashMap;
+    /// let mut factories: HashMap<&str, Box<dyn Fn() -> Box<dyn Any>>> = HashMap::new();
+    /// factories.insert("port", Box::new(|| Box::new(8080u16)));
+    /// factories.insert("port", Box::new(|| Box::new(8081u16)));
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub MISMATCHED_ANY_FACTORY,
+    suspicious,
+    "replacing a factory returning `Box<dyn Any>` with one returning a different type"
+}
+declare_lint_pass!(MismatchedAnyFactory => [MISMATCHED_ANY_FACTORY]);
+
+/// A factory stored in a map or `Vec`.
+struct Stored<'tcx> {
+    container: &'tcx Expr<'tcx>,
+    key: &'tcx Expr<'tcx>,
+    ty: Ty<'tcx>,
+    span: Span,
+    /// The locals the container and the key refer to
+    locals: Vec<HirId>,
+}
+
+impl<'tcx> LateLintPass<'tcx> for MismatchedAnyFactory {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        let mut stored: Vec<Stored<'tcx>> = Vec::new();
+        for stmt in block.stmts {
+            let (StmtKind::Semi(expr) | StmtKind::Expr(expr)) = stmt.kind else {
+                forget_mentioned(cx, &mut stored, stmt);
+                continue;
+            };
+            let Some(new) = stored_factory(cx, expr) else {
+                // The factory may have been removed, or the key changed
+                forget_mentioned(cx, &mut stored, stmt);
+                continue;
+            };
+            // Only the latest factory under the key is replaced
+            if let Some(index) = stored
+                .iter()
+                .rposition(|old| eq_expr_value(cx, old.container, new.container) && eq_expr_value(cx, old.key, new.key))
+            {
+                let old = stored.remove(index);
+                if old.ty != new.ty && !expr.span.from_expansion() {
+                    span_lint_and_then(
+                        cx,
+                        MISMATCHED_ANY_FACTORY,
+                        new.span,
+                        format!(
+                            "this factory for `{}` returns `{}`, but the factory it replaces returns `{}`",
+                            snippet(cx, new.key.span, ".."),
+                            new.ty,
+                            old.ty,
+                        ),
+                        |diag| {
+                            diag.span_note(old.span, "the replaced factory is stored here");
+                        },
+                    );
+                }
+            }
+            stored.push(new);
+        }
+    }
+}
+
+/// Forgets the stored factories whose container or key refers to a local used in the statement.
+fn forget_mentioned<'tcx>(cx: &LateContext<'tcx>, stored: &mut Vec<Stored<'tcx>>, stmt: &'tcx Stmt<'tcx>) {
+    stored.retain(|old| !old.locals.iter().any(|&id| is_local_used(cx, stmt, id)));
+}
+
+/// If the expression inserts a factory into a map or assigns one to an index of a `Vec`,
+/// returns the container, the key or index and the type of the value the factory returns.
+fn stored_factory<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<Stored<'tcx>> {
+    let (container, key, factory) = match expr.kind {
+        ExprKind::MethodCall(method, container, [key, factory], _)
+            if method.ident.name == sym::insert
+                && let ty::Adt(adt, _) = *cx.typeck_results().expr_ty_adjusted(container).peel_refs().kind()
+                && matches!(
+                    cx.tcx.get_diagnostic_name(adt.did()),
+                    Some(sym::HashMap | sym::BTreeMap)
+                ) =>
+        {
+            (container, key, factory)
+        },
+        ExprKind::Assign(lhs, factory, _)
+            if let ExprKind::Index(container, index, _) = lhs.kind
+                && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(container).peel_refs(), sym::Vec) =>
+        {
+            (container, index, factory)
+        },
+        _ => return None,
+    };
+
+    // `Box::new(|| Box::new(value))`, possibly with the closure bound to a local
+    let ExprKind::Call(func, [closure]) = factory.kind else {
+        return None;
+    };
+    if !is_path_diagnostic_item(cx, func, sym::box_new) {
+        return None;
+    }
+    let ExprKind::Closure(closure) = expr_or_init(cx, closure).kind else {
+        return None;
+    };
+    let mut value = cx.tcx.hir_body(closure.body).value;
+    if let ExprKind::Block(
+        Block {
+            stmts: [],
+            expr: Some(tail),
+            ..
+        },
+        _,
+    ) = value.kind
+    {
+        value = tail;
+    }
+    let typeck = cx.typeck_results();
+    if let ExprKind::Call(func, [arg]) = value.kind
+        && is_path_diagnostic_item(cx, func, sym::box_new)
+        && typeck
+            .expr_ty_adjusted(value)
+            .boxed_ty()
+            .is_some_and(|ty| is_dyn_any(cx.tcx, ty))
+        && let ty = typeck.expr_ty(arg)
+        && !ty.has_param()
+        && !is_dyn_any(cx.tcx, ty)
+    {
+        let mut locals = Vec::new();
+        for expr in [container, key] {
+            for_each_expr_without_closures(expr, |e| {
+                if let Some(id) = path_to_local(e) {
+                    locals.push(id);
+                }
+                ControlFlow::<()>::Continue(())
+            });
+        }
+        Some(Stored {
+            container,
+            key,
+            ty: cx.tcx.erase_regions(ty),
+            span: factory.span,
+            locals,
+        })
+    } else {
+        None
+    }
+}
@@ -0,0 +1,259 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::SpanRangeExt;
+use clippy_utils::ty::{any_downcast, is_dyn_any};
+use clippy_utils::visitors::{for_each_expr, for_each_expr_without_closures};
+use clippy_utils::{get_parent_expr, is_path_diagnostic_item, path_to_local};
+use core::ops::ControlFlow;
+use rustc_ast::{LitFloatType, LitIntType, LitKind};
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_hir::{BindingMode, Body, Expr, ExprKind, HirId, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty, TypeVisitableExt};
+use rustc_session::declare_lint_pass;
+use rustc_span::{Span, sym};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for downcasts of a local `Box<dyn Any>` to a type other than the one it was
+    /// created from.
+    ///
+    /// ### Why is this bad?
+    /// The local can't hold anything but the value it was initialized with, so the downcast
+    /// will always fail. This is usually a typo or a leftover from a refactoring.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// let value: Box<dyn Any> = Box::new(0u64);
+    /// let n = value.downcast_ref::<u32>();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::any::Any;
+    /// let value: Box<dyn Any> = Box::new(0u64);
+    /// let n = value.downcast_ref::<u64>();
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub MISMATCHED_DOWNCAST,
+    correctness,
+    "downcasting a `Box<dyn Any>` to a type other than the one it was created from"
+}
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for local `Box<dyn Any>`s created from a value of a concrete type, whose every use
+    /// downcasts it back to that same type, including in closures they are moved into.
+    ///
+    /// ### Why is this bad?
+    /// The type of the value is known where the box is created and where it is downcast, so
+    /// erasing it only adds an allocation and a runtime check. The value can be used, or moved
+    /// into the closure, directly.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// let payload: Box<dyn Any + Send> = Box::new(42u32);
+    /// std::thread::spawn(move || {
+    ///     let n = payload.downcast::<u32>().unwrap();
+    ///     println!("{n}");
+    /// });
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let payload = 42u32;
+    /// std::thread::spawn(move || {
+    ///     println!("{payload}");
+    /// });
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub BOX_ANY_ROUND_TRIP,
+    pedantic,
+    "boxing a value into a `Box<dyn Any>` only to downcast it back to the same type"
+}
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for downcasts to a type parameter of a local `Box<dyn Any>` created from a
+    /// value of a concrete type in a generic function.
+    ///
+    /// ### Why is this bad?
+    /// The downcast only succeeds for the one instantiation of the type parameter which is
+    /// that concrete type, and fails for every other. This is either a mistake, or an obscure
+    /// way to check if the type parameter is that type.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// fn parse<T: 'static>(input: &str) -> Option<T> {
+    ///     let value: Box<dyn Any> = Box::new(input.len());
+    ///     value.downcast::<T>().ok().map(|value| *value)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn parse<T: From<usize>>(input: &str) -> Option<T> {
+    ///     Some(T::from(input.len()))
+    /// }
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub GENERIC_DOWNCAST_OF_KNOWN_TYPE,
+    pedantic,
+    "downcasting a `Box<dyn Any>` of a known type to a type parameter"
+}
+
+declare_lint_pass!(MismatchedDowncast => [MISMATCHED_DOWNCAST, BOX_ANY_ROUND_TRIP, GENERIC_DOWNCAST_OF_KNOWN_TYPE]);
+
+impl<'tcx> LateLintPass<'tcx> for MismatchedDowncast {
+    fn check_body(&mut self, cx: &LateContext<'tcx>, body: &Body<'tcx>) {
+        let boxed_locals = boxed_any_locals(cx, body.value);
+        if boxed_locals.is_empty() {
+            return;
+        }
+
+        for_each_expr_without_closures(body.value, |e| {
+            if let Some((_, recv, target)) = any_downcast(cx, e)
+                && !e.span.from_expansion()
+                && let Some(local_id) = path_to_local(recv)
+                && let Some(&(boxed_ty, boxed_span, value)) = boxed_locals.get(&local_id)
+            {
+                if let ty::Param(_) = *target.kind() {
+                    span_lint_and_then(
+                        cx,
+                        GENERIC_DOWNCAST_OF_KNOWN_TYPE,
+                        e.span,
+                        format!("downcasting to `{target}` only succeeds if `{target}` is `{boxed_ty}`"),
+                        |diag| {
+                            diag.span_note(boxed_span, format!("the value was created from a `{boxed_ty}` here"));
+                            diag.help(format!(
+                                "to check if `{target}` is `{boxed_ty}`, compare their `TypeId`s instead"
+                            ));
+                        },
+                    );
+                } else if !target.has_param() && cx.tcx.erase_regions(target) != cx.tcx.erase_regions(boxed_ty) {
+                    span_lint_and_then(
+                        cx,
+                        MISMATCHED_DOWNCAST,
+                        e.span,
+                        format!("downcasting to `{target}` will always fail"),
+                        |diag| {
+                            diag.span_note(boxed_span, format!("the value was created from a `{boxed_ty}` here"));
+                            if let ExprKind::Lit(lit) = value.kind
+                                && (matches!(lit.node, LitKind::Int(_, LitIntType::Unsuffixed)) && target.is_integral()
+                                    || matches!(lit.node, LitKind::Float(_, LitFloatType::Unsuffixed))
+                                        && target.is_floating_point())
+                                && let Some(lit_snippet) = value.span.get_source_text(cx)
+                            {
+                                diag.span_help(
+                                    value.span,
+                                    format!(
+                                        "this literal has no suffix, so its type is inferred as `{boxed_ty}`; \
+                                        to box a `{target}`, write `{lit_snippet}{target}`"
+                                    ),
+                                );
+                            }
+                        },
+                    );
+                }
+            }
+            ControlFlow::<()>::Continue(())
+        });
+
+        check_round_trips(cx, body, &boxed_locals);
+    }
+}
+
+/// Lints the boxed locals whose every use, including in closures, is a downcast to the type they
+/// were created from.
+fn check_round_trips<'tcx>(
+    cx: &LateContext<'tcx>,
+    body: &Body<'tcx>,
+    boxed_locals: &FxIndexMap<HirId, (Ty<'tcx>, Span, &'tcx Expr<'tcx>)>,
+) {
+    // The spans of the downcasts of each local, or `None` if it is used in any other way
+    let mut downcasts: FxIndexMap<HirId, Option<Vec<Span>>> =
+        boxed_locals.keys().map(|&id| (id, Some(Vec::new()))).collect();
+    for_each_expr(cx, body.value, |e| {
+        if let Some(local_id) = path_to_local(e)
+            && let Some(entry) = downcasts.get_mut(&local_id)
+            && let Some(spans) = entry
+        {
+            let (boxed_ty, ..) = boxed_locals[&local_id];
+            if let Some(parent) = get_parent_expr(cx, e)
+                && let Some((_, recv, target)) = any_downcast(cx, parent)
+                && recv.hir_id == e.hir_id
+                && !parent.span.from_expansion()
+                && cx.tcx.erase_regions(target) == cx.tcx.erase_regions(boxed_ty)
+            {
+                spans.push(parent.span);
+            } else {
+                *entry = None;
+            }
+        }
+        ControlFlow::<()>::Continue(())
+    });
+
+    for (local_id, spans) in downcasts {
+        if let Some(spans) = spans
+            && !spans.is_empty()
+        {
+            let (boxed_ty, boxed_span, _) = boxed_locals[&local_id];
+            span_lint_and_then(
+                cx,
+                BOX_ANY_ROUND_TRIP,
+                boxed_span,
+                format!("this `{boxed_ty}` is boxed into a `dyn Any` only to be downcast back"),
+                |diag| {
+                    diag.span_note(spans, format!("every use downcasts it to `{boxed_ty}`"));
+                    diag.help(format!("use the `{boxed_ty}` directly"));
+                },
+            );
+        }
+    }
+}
+
+/// Collects the immutable locals initialized with `Box::new(value)` coerced to `Box<dyn Any>`,
+/// along with the type of `value`, the span of the `Box::new` call and `value` itself.
+fn boxed_any_locals<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+) -> FxIndexMap<HirId, (Ty<'tcx>, Span, &'tcx Expr<'tcx>)> {
+    let mut boxed_locals = FxIndexMap::default();
+    for_each_expr_without_closures(expr, |e| {
+        if let ExprKind::Block(block, _) = e.kind {
+            for stmt in block.stmts {
+                // Only immutable bindings can't be reassigned or have their contents replaced
+                if let StmtKind::Let(local) = stmt.kind
+                    && let PatKind::Binding(BindingMode::NONE, id, _, None) = local.pat.kind
+                    && let Some(init) = local.init
+                    && let Some(boxed) = boxed_any_source(cx, init)
+                {
+                    boxed_locals.insert(id, boxed);
+                }
+            }
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    boxed_locals
+}
+
+/// If `init` is `Box::new(value)` (optionally cast) producing a `Box<dyn Any>`, returns the type
+/// of `value`, the span of the call and `value`.
+fn boxed_any_source<'tcx>(
+    cx: &LateContext<'tcx>,
+    init: &'tcx Expr<'tcx>,
+) -> Option<(Ty<'tcx>, Span, &'tcx Expr<'tcx>)> {
+    let typeck = cx.typeck_results();
+    let call = match init.kind {
+        ExprKind::Cast(inner, _) => inner,
+        _ => init,
+    };
+    if let ExprKind::Call(func, [arg]) = call.kind
+        && is_path_diagnostic_item(cx, func, sym::box_new)
+        && is_dyn_any(cx.tcx, typeck.expr_ty_adjusted(init).boxed_ty()?)
+        && let boxed_ty = typeck.expr_ty(arg)
+        && !boxed_ty.has_param()
+        && !is_dyn_any(cx.tcx, boxed_ty)
+    {
+        Some((boxed_ty, call.span, arg))
+    } else {
+        None
+    }
+}
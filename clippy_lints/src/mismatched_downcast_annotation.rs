@@ -0,0 +1,82 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::sym;
+use clippy_utils::ty::any_downcast;
+use rustc_hir::{Expr, ExprKind, LetStmt};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `let` bindings initialized with a downcast of a `dyn Any` whose type
+    /// annotation differs from the type given in the turbofish of the downcast.
+    ///
+    /// ### Why is this bad?
+    /// Such code only compiles because the downcast value is coerced to the annotated type,
+    /// e.g. from `Box<u32>` to `Box<dyn Debug>`. Reading either type alone gives the wrong
+    /// impression of which type is checked for and which type the binding has.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// # use std::fmt::Debug;
+    /// # let value: Box<dyn Any> = Box::new(0u32);
+    /// let n: Box<dyn Debug> = value.downcast::<u32>().unwrap();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::any::Any;
+    /// # use std::fmt::Debug;
+    /// # let value: Box<dyn Any> = Box::new(0u32);
+    /// let n: Box<u32> = value.downcast().unwrap();
+    /// let n: Box<dyn Debug> = n;
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub MISMATCHED_DOWNCAST_ANNOTATION,
+    pedantic,
+    "type annotation of a binding differing from the type it was downcast to"
+}
+declare_lint_pass!(MismatchedDowncastAnnotation => [MISMATCHED_DOWNCAST_ANNOTATION]);
+
+impl<'tcx> LateLintPass<'tcx> for MismatchedDowncastAnnotation {
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx LetStmt<'tcx>) {
+        if let Some(hir_ty) = local.ty
+            && let Some(init) = local.init
+            && !local.span.from_expansion()
+            && let Some(turbofish_span) = downcast_turbofish(cx, init)
+            && let annotated_ty = cx.typeck_results().pat_ty(local.pat)
+            && let init_ty = cx.typeck_results().expr_ty(init)
+            && cx.tcx.erase_regions(annotated_ty) != cx.tcx.erase_regions(init_ty)
+        {
+            span_lint_and_then(
+                cx,
+                MISMATCHED_DOWNCAST_ANNOTATION,
+                hir_ty.span,
+                format!("the type annotation `{annotated_ty}` differs from the downcast result `{init_ty}`"),
+                |diag| {
+                    diag.span_note(turbofish_span, "the type to downcast to is given here");
+                    diag.help(format!("annotate the binding as `{init_ty}` and coerce it separately"));
+                },
+            );
+        }
+    }
+}
+
+/// If the expression is a downcast with an explicit turbofish, optionally followed by `unwrap`
+/// or `expect`, returns the span of the type given in the turbofish.
+fn downcast_turbofish<'tcx>(cx: &LateContext<'tcx>, mut expr: &'tcx Expr<'tcx>) -> Option<Span> {
+    if let ExprKind::MethodCall(path, recv, ..) = expr.kind
+        && matches!(path.ident.name, sym::unwrap | sym::expect)
+    {
+        expr = recv;
+    }
+    if let ExprKind::MethodCall(path, ..) = expr.kind
+        && any_downcast(cx, expr).is_some_and(|(name, ..)| name != sym::is)
+        && let Some(args) = path.args
+        && let [arg] = args.args
+    {
+        Some(arg.span())
+    } else {
+        None
+    }
+}
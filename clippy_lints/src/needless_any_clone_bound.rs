@@ -0,0 +1,295 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::is_trait_impl_item;
+use clippy_utils::visitors::for_each_expr;
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, ExprKind, FnDecl, GenericBound, TraitBoundModifiers, WherePredicateKind};
+use rustc_hir_analysis::lower_ty;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::adjustment::{Adjust, PointerCoercion};
+use rustc_middle::ty::{self, Clause, ClauseKind, GenericArg, GenericArgsRef, Ty, TypeVisitableExt};
+use rustc_session::declare_lint_pass;
+use rustc_span::{Span, sym};
+use rustc_trait_selection::traits::supertrait_def_ids;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Any` and `Clone` bounds on the type parameters of a function which are not
+    /// needed by its body, e.g. a `T: Any + Clone` whose values are never cloned.
+    ///
+    /// ### Why is this bad?
+    /// The unused bounds needlessly restrict the types the function can be called with.
+    ///
+    /// ### Known problems
+    /// The lint is conservative: if the parameter is used in a way that might need one of the
+    /// bounds, such as calling a generic function with it or coercing it to a trait object,
+    /// both bounds are considered used.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// fn erase<T: Any + Clone>(value: T) -> Box<dyn Any> {
+    ///     Box::new(value)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::any::Any;
+    /// fn erase<T: Any>(value: T) -> Box<dyn Any> {
+    ///     Box::new(value)
+    /// }
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub NEEDLESS_ANY_CLONE_BOUND,
+    pedantic,
+    "`Any` or `Clone` bounds on a type parameter that the function does not need"
+}
+declare_lint_pass!(NeedlessAnyCloneBound => [NEEDLESS_ANY_CLONE_BOUND]);
+
+impl<'tcx> LateLintPass<'tcx> for NeedlessAnyCloneBound {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'tcx>,
+        body: &'tcx Body<'tcx>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        if matches!(kind, FnKind::Closure)
+            || span.from_expansion()
+            // The bounds are dictated by the trait
+            || is_trait_impl_item(cx, cx.tcx.local_def_id_to_hir_id(def_id))
+            || cx.tcx.trait_of_item(def_id.to_def_id()).is_some()
+        {
+            return;
+        }
+        let (Some(clone_trait), Some(any_trait)) =
+            (cx.tcx.lang_items().clone_trait(), cx.tcx.get_diagnostic_item(sym::Any))
+        else {
+            return;
+        };
+        let Some(generics) = cx.tcx.hir_get_generics(def_id) else {
+            return;
+        };
+
+        for (predicate_pos, predicate) in generics.predicates.iter().enumerate() {
+            let WherePredicateKind::BoundPredicate(bound_predicate) = &predicate.kind else {
+                continue;
+            };
+            let Some((_, ident)) = bound_predicate.bounded_ty.as_generic_param() else {
+                continue;
+            };
+            let mut bound_uses = None;
+            for (bound_pos, bound) in bound_predicate.bounds.iter().enumerate() {
+                if let GenericBound::Trait(trait_bound) = bound
+                    && trait_bound.modifiers == TraitBoundModifiers::NONE
+                    && !trait_bound.span.from_expansion()
+                    && let Some(trait_def_id) = trait_bound.trait_ref.trait_def_id()
+                    && (trait_def_id == clone_trait || trait_def_id == any_trait)
+                {
+                    let uses = bound_uses.get_or_insert_with(|| {
+                        let param = lower_ty(cx.tcx, bound_predicate.bounded_ty);
+                        BoundUses::collect(cx, body, def_id, param, clone_trait, any_trait)
+                    });
+                    let is_used = if trait_def_id == clone_trait {
+                        uses.clone
+                    } else {
+                        uses.any
+                    };
+                    if !is_used {
+                        let trait_name = cx.tcx.item_name(trait_def_id);
+                        span_lint_and_then(
+                            cx,
+                            NEEDLESS_ANY_CLONE_BOUND,
+                            trait_bound.span,
+                            format!("the `{trait_name}` bound on `{ident}` is not needed by the function"),
+                            |diag| {
+                                diag.span_suggestion_verbose(
+                                    generics.span_for_bound_removal(predicate_pos, bound_pos),
+                                    "remove the bound",
+                                    "",
+                                    Applicability::MaybeIncorrect,
+                                );
+                            },
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Which of the `Clone` and `Any` bounds of a type parameter may be required by a function.
+#[derive(Default)]
+struct BoundUses {
+    clone: bool,
+    any: bool,
+}
+
+impl BoundUses {
+    fn collect<'tcx>(
+        cx: &LateContext<'tcx>,
+        body: &'tcx Body<'tcx>,
+        def_id: LocalDefId,
+        param: Ty<'tcx>,
+        clone_trait: DefId,
+        any_trait: DefId,
+    ) -> Self {
+        let mut visitor = BoundUseVisitor {
+            cx,
+            param,
+            clone_trait,
+            any_trait,
+            uses: Self::default(),
+        };
+
+        // The other bounds of the function, which may have `Clone` or `Any` as a supertrait
+        for &(clause, _) in cx.tcx.predicates_of(def_id).predicates {
+            if let ClauseKind::Trait(pred) = clause.kind().skip_binder()
+                && pred.self_ty() == param
+                && (pred.def_id() == clone_trait || pred.def_id() == any_trait)
+            {
+                continue;
+            }
+            visitor.check_clause(clause);
+        }
+        let sig = cx.tcx.fn_sig(def_id).instantiate_identity().skip_binder();
+        for ty in sig.inputs_and_output {
+            visitor.check_ty(ty);
+        }
+
+        let typeck = cx.typeck_results();
+        for (_, &ty) in typeck.node_types().items_in_stable_order() {
+            visitor.check_ty(ty);
+        }
+        for_each_expr(cx, body.value, |e| {
+            if let Some(def_id) = typeck.type_dependent_def_id(e.hir_id) {
+                visitor.check_def(def_id, typeck.node_args(e.hir_id));
+            }
+            let mut source = typeck.expr_ty(e);
+            for adjustment in typeck.expr_adjustments(e) {
+                if visitor.mentions_param(source) {
+                    match adjustment.kind {
+                        Adjust::Pointer(PointerCoercion::Unsize) => visitor.check_unsize(adjustment.target),
+                        Adjust::Pointer(_) | Adjust::Deref(Some(_)) => visitor.require_all(),
+                        _ => {},
+                    }
+                }
+                source = adjustment.target;
+            }
+            if let ExprKind::Cast(inner, _) = e.kind
+                && visitor.mentions_param(typeck.expr_ty(inner))
+            {
+                visitor.check_unsize(typeck.expr_ty(e));
+            }
+            ControlFlow::<()>::Continue(())
+        });
+
+        visitor.uses
+    }
+}
+
+struct BoundUseVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    param: Ty<'tcx>,
+    clone_trait: DefId,
+    any_trait: DefId,
+    uses: BoundUses,
+}
+
+impl<'tcx> BoundUseVisitor<'_, 'tcx> {
+    fn mentions_param(&self, ty: Ty<'tcx>) -> bool {
+        ty.walk().any(|arg| arg == self.param.into())
+    }
+
+    fn require_all(&mut self) {
+        self.uses.clone = true;
+        self.uses.any = true;
+    }
+
+    /// Records which bounds a clause may need, assuming that those of the parameter itself are
+    /// only needed through its supertraits.
+    fn check_clause(&mut self, clause: Clause<'tcx>) {
+        match clause.kind().skip_binder() {
+            ClauseKind::Trait(pred) => {
+                let [self_arg, other_args @ ..] = pred.trait_ref.args.as_slice() else {
+                    return;
+                };
+                if !pred
+                    .trait_ref
+                    .args
+                    .iter()
+                    .any(|arg| arg.walk().any(|a| a == self.param.into()))
+                {
+                    return;
+                }
+                if *self_arg == self.param.into() && !other_args.iter().any(TypeVisitableExt::has_param) {
+                    for supertrait in supertrait_def_ids(self.cx.tcx, pred.def_id()) {
+                        self.uses.clone |= supertrait == self.clone_trait;
+                        self.uses.any |= supertrait == self.any_trait;
+                    }
+                } else {
+                    // e.g. `Vec<T>: Clone` may be implemented with `T: Clone`
+                    self.require_all();
+                }
+            },
+            // e.g. `T: 'static` is implied by `T: Any`
+            kind => {
+                if kind.has_param() {
+                    self.require_all();
+                }
+            },
+        }
+    }
+
+    fn check_def(&mut self, def_id: DefId, args: GenericArgsRef<'tcx>) {
+        if args.iter().any(|arg| arg.walk().any(|a| a == self.param.into())) {
+            for clause in self
+                .cx
+                .tcx
+                .predicates_of(def_id)
+                .instantiate(self.cx.tcx, args)
+                .predicates
+            {
+                self.check_clause(clause);
+            }
+        }
+    }
+
+    /// Records the bounds needed to coerce a value mentioning the parameter to `target`. Only
+    /// coercions to `dyn Any` are known to rely on nothing but the `Any` bound.
+    fn check_unsize(&mut self, target: Ty<'tcx>) {
+        let mut objects = target
+            .walk()
+            .filter_map(GenericArg::as_type)
+            .filter_map(|ty| match ty.kind() {
+                ty::Dynamic(preds, ..) => Some(preds.principal_def_id()),
+                _ => None,
+            });
+        if let Some(first) = objects.next()
+            && first == Some(self.any_trait)
+            && objects.all(|principal| principal == Some(self.any_trait))
+        {
+            self.uses.any = true;
+        } else {
+            self.require_all();
+        }
+    }
+
+    fn check_ty(&mut self, ty: Ty<'tcx>) {
+        if !self.mentions_param(ty) {
+            return;
+        }
+        for ty in ty.walk().filter_map(GenericArg::as_type) {
+            match *ty.kind() {
+                ty::Adt(adt, args) => self.check_def(adt.did(), args),
+                ty::FnDef(def_id, args) => self.check_def(def_id, args),
+                ty::Alias(..) | ty::Dynamic(..) if self.mentions_param(ty) => self.require_all(),
+                _ => {},
+            }
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::is_dyn_any;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{is_trait_impl_item, path_to_local_id};
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::{FxHashSet, FxIndexMap};
+use rustc_errors::Applicability;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{
+    BindingMode, Body, BorrowKind, Expr, ExprKind, FnDecl, HirId, Mutability, Node, PatKind, TyKind, UnOp,
+};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_middle::ty::adjustment::{Adjust, Adjustment, AutoBorrow, AutoBorrowMutability};
+use rustc_session::impl_lint_pass;
+use rustc_span::Span;
+use rustc_span::def_id::LocalDefId;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for function parameters of type `&mut dyn Any` which are only used through
+    /// shared references, e.g. by calling `downcast_ref` or `type_id` on them.
+    ///
+    /// ### Why is this bad?
+    /// The value is never mutated, so `&dyn Any` would suffice. Requiring a mutable reference
+    /// needlessly restricts the callers.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// fn count(value: &mut dyn Any) -> Option<u32> {
+    ///     value.downcast_ref::<u32>().copied()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::any::Any;
+    /// fn count(value: &dyn Any) -> Option<u32> {
+    ///     value.downcast_ref::<u32>().copied()
+    /// }
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub NEEDLESS_MUT_ANY,
+    pedantic,
+    "a `&mut dyn Any` parameter that is never used mutably"
+}
+
+pub struct NeedlessMutAny<'tcx> {
+    avoid_breaking_exported_api: bool,
+    /// Functions used other than by calling them, e.g. coerced to a function pointer
+    used_fn_def_ids: FxHashSet<LocalDefId>,
+    fn_def_ids_to_needless_mut: FxIndexMap<LocalDefId, Vec<&'tcx rustc_hir::Ty<'tcx>>>,
+}
+
+impl NeedlessMutAny<'_> {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
+            used_fn_def_ids: FxHashSet::default(),
+            fn_def_ids_to_needless_mut: FxIndexMap::default(),
+        }
+    }
+}
+
+impl_lint_pass!(NeedlessMutAny<'_> => [NEEDLESS_MUT_ANY]);
+
+impl<'tcx> LateLintPass<'tcx> for NeedlessMutAny<'tcx> {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl<'tcx>,
+        body: &'tcx Body<'tcx>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        if matches!(kind, FnKind::Closure)
+            || span.from_expansion()
+            // The signature is dictated by the trait
+            || is_trait_impl_item(cx, cx.tcx.local_def_id_to_hir_id(def_id))
+            || cx.tcx.trait_of_item(def_id.to_def_id()).is_some()
+            || (self.avoid_breaking_exported_api && cx.effective_visibilities.is_exported(def_id))
+        {
+            return;
+        }
+
+        let sig = cx.tcx.fn_sig(def_id).instantiate_identity().skip_binder();
+        for ((hir_ty, &param_ty), param) in decl.inputs.iter().zip(sig.inputs()).zip(body.params) {
+            if let ty::Ref(_, pointee, Mutability::Mut) = *param_ty.kind()
+                && is_dyn_any(cx.tcx, pointee)
+                && let PatKind::Binding(BindingMode::NONE, binding_id, ..) = param.pat.kind
+                && let TyKind::Ref(..) = hir_ty.kind
+                && !hir_ty.span.from_expansion()
+                && is_only_reborrowed(cx, body, binding_id)
+            {
+                self.fn_def_ids_to_needless_mut.entry(def_id).or_default().push(hir_ty);
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        // A function coerced to a function pointer or passed as a callback must keep its signature
+        if let ExprKind::Path(..) = expr.kind
+            && let ty::FnDef(def_id, _) = *cx.typeck_results().expr_ty(expr).kind()
+            && let Some(def_id) = def_id.as_local()
+            && !matches!(
+                cx.tcx.parent_hir_node(expr.hir_id),
+                Node::Expr(Expr { kind: ExprKind::Call(callee, _), .. }) if callee.hir_id == expr.hir_id
+            )
+        {
+            self.used_fn_def_ids.insert(def_id);
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for (fn_def_id, hir_tys) in self
+            .fn_def_ids_to_needless_mut
+            .iter()
+            .filter(|(def_id, _)| !self.used_fn_def_ids.contains(def_id))
+        {
+            for hir_ty in hir_tys {
+                let TyKind::Ref(lifetime, mut_ty) = hir_ty.kind else {
+                    continue;
+                };
+                span_lint_hir_and_then(
+                    cx,
+                    NEEDLESS_MUT_ANY,
+                    cx.tcx.local_def_id_to_hir_id(*fn_def_id),
+                    hir_ty.span,
+                    "this `dyn Any` is taken by mutable reference, but never mutated",
+                    |diag| {
+                        let lifetime = if lifetime.is_anonymous() {
+                            String::new()
+                        } else {
+                            format!("{} ", lifetime.ident)
+                        };
+                        let mut applicability = Applicability::MaybeIncorrect;
+                        let pointee = snippet_with_applicability(cx, mut_ty.ty.span, "..", &mut applicability);
+                        // Trait objects with additional bounds, like `dyn Any + Send`, need parentheses
+                        let sugg = match mut_ty.ty.kind {
+                            TyKind::TraitObject(bounds, lt_bound) if bounds.len() > 1 || !lt_bound.is_elided() => {
+                                format!("&{lifetime}({pointee})")
+                            },
+                            _ => format!("&{lifetime}{pointee}"),
+                        };
+                        diag.span_suggestion_verbose(hir_ty.span, "use a shared reference", sugg, applicability);
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Checks if the binding is used, and every use immutably reborrows the `dyn Any` it points to,
+/// as is done when calling `downcast_ref` or passing it where a `&dyn Any` is expected.
+fn is_only_reborrowed<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Body<'tcx>, binding_id: HirId) -> bool {
+    let mut is_used = false;
+    for_each_expr(cx, body.value, |e| {
+        if !path_to_local_id(e, binding_id) {
+            return ControlFlow::Continue(());
+        }
+        is_used = true;
+        if is_shared_reborrow(cx, e) {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    })
+    .is_none()
+        && is_used
+}
+
+fn is_shared_reborrow(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    let typeck = cx.typeck_results();
+    match typeck.expr_adjustments(e) {
+        [
+            Adjustment {
+                kind: Adjust::Deref(None),
+                ..
+            },
+            Adjustment {
+                kind: Adjust::Borrow(AutoBorrow::Ref(AutoBorrowMutability::Not)),
+                ..
+            },
+            ..,
+        ] => true,
+        // An explicit `*value`, borrowed by `&*value` or by autoref
+        [] => {
+            if let Node::Expr(parent) = cx.tcx.parent_hir_node(e.hir_id)
+                && let ExprKind::Unary(UnOp::Deref, _) = parent.kind
+            {
+                match typeck.expr_adjustments(parent) {
+                    [
+                        Adjustment {
+                            kind: Adjust::Borrow(AutoBorrow::Ref(AutoBorrowMutability::Not)),
+                            ..
+                        },
+                        ..,
+                    ] => true,
+                    [] => matches!(
+                        cx.tcx.parent_hir_node(parent.hir_id),
+                        Node::Expr(Expr {
+                            kind: ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _),
+                            ..
+                        })
+                    ),
+                    _ => false,
+                }
+            } else {
+                false
+            }
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,167 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::{is_panic, root_macro_call_first_node};
+use clippy_utils::peel_blocks;
+use clippy_utils::ty::{AutoTraitSet, dyn_any_auto_traits};
+use rustc_hir::{Block, Expr, ExprKind, ImplItemKind, Item, ItemKind, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::declare_lint_pass;
+use rustc_span::{Span, sym};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for hand-written `Clone` implementations of structs with a `Box<dyn Any>` field
+    /// which panic instead of cloning that field, e.g. with `unimplemented!()`.
+    ///
+    /// ### Why is this bad?
+    /// `Box<dyn Any>` can't be cloned, which is usually why `Clone` couldn't be derived. A
+    /// `Clone` implementation that panics looks usable but fails at runtime. If the value only
+    /// needs to be read after cloning, an `Rc<dyn Any>` (or `Arc<dyn Any + Send + Sync>`) can be
+    /// cloned by sharing it.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// struct Event {
+    ///     name: String,
+    ///     payload: Box<dyn Any>,
+    /// }
+    ///
+    /// impl Clone for Event {
+    ///     fn clone(&self) -> Self {
+    ///         Self {
+    ///             name: self.name.clone(),
+    ///             payload: unimplemented!(),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::any::Any;
+    /// # use std::rc::Rc;
+    /// #[derive(Clone)]
+    /// struct Event {
+    ///     name: String,
+    ///     payload: Rc<dyn Any>,
+    /// }
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub PANICKING_ANY_CLONE,
+    suspicious,
+    "a `Clone` implementation that panics instead of cloning a `Box<dyn Any>` field"
+}
+declare_lint_pass!(PanickingAnyClone => [PANICKING_ANY_CLONE]);
+
+impl<'tcx> LateLintPass<'tcx> for PanickingAnyClone {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if let ItemKind::Impl(imp) = item.kind
+            && let Some(trait_ref) = imp.of_trait
+            && !item.span.from_expansion()
+            && trait_ref.trait_def_id() == cx.tcx.lang_items().clone_trait()
+            && let ty::Adt(adt, args) = *cx.tcx.type_of(item.owner_id).instantiate_identity().kind()
+            && adt.is_struct()
+            && let Some(clone_item) = imp.items.iter().find(|impl_item| impl_item.ident.name == sym::clone)
+            && let ImplItemKind::Fn(_, body_id) = cx.tcx.hir_impl_item(clone_item.id).kind
+        {
+            let any_fields: Vec<_> = adt
+                .all_fields()
+                .filter_map(|field| {
+                    let auto_traits = dyn_any_auto_traits(cx.tcx, field.ty(cx.tcx, args).boxed_ty()?)?;
+                    Some((field, auto_traits))
+                })
+                .collect();
+            if any_fields.is_empty() {
+                return;
+            }
+
+            let body = cx.tcx.hir_body(body_id).value;
+            if let Some(panic_span) = panic_macro_span(cx, body) {
+                span_lint_and_then(
+                    cx,
+                    PANICKING_ANY_CLONE,
+                    panic_span,
+                    "this `Clone` implementation panics instead of cloning",
+                    |diag| {
+                        let (any_field, auto_traits) = any_fields[0];
+                        diag.span_note(
+                            cx.tcx.def_span(any_field.did),
+                            "this `Box<dyn Any>` field can't be cloned",
+                        );
+                        diag.help(shared_any_help(auto_traits));
+                    },
+                );
+            } else if let ExprKind::Struct(_, fields, _) = peel_blocks(body).kind {
+                for field in fields {
+                    if let Some(&(any_field, auto_traits)) = any_fields.iter().find(|(f, _)| f.name == field.ident.name)
+                        && let Some(panic_span) = panic_macro_span(cx, field.expr)
+                    {
+                        span_lint_and_then(
+                            cx,
+                            PANICKING_ANY_CLONE,
+                            panic_span,
+                            format!(
+                                "this `Clone` implementation panics instead of cloning `{}`",
+                                field.ident
+                            ),
+                            |diag| {
+                                diag.span_note(
+                                    cx.tcx.def_span(any_field.did),
+                                    "this `Box<dyn Any>` field can't be cloned",
+                                );
+                                diag.help(shared_any_help(auto_traits));
+                            },
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Suggests a shared pointer to store the value in, keeping it `Send` if the field was.
+fn shared_any_help(auto_traits: AutoTraitSet) -> &'static str {
+    if auto_traits.send {
+        "consider storing the value in an `Arc<dyn Any + Send + Sync>`, which can be cloned by sharing it"
+    } else {
+        "consider storing the value in an `Rc<dyn Any>`, which can be cloned by sharing it"
+    }
+}
+
+/// If the expression is a call to `panic!`, `todo!`, `unimplemented!` or `unreachable!`, possibly
+/// in a block, returns the span of the macro call.
+fn panic_macro_span(cx: &LateContext<'_>, mut expr: &Expr<'_>) -> Option<Span> {
+    loop {
+        // The expansion may itself be a block, so check for the macro before peeling
+        if let Some(macro_call) = root_macro_call_first_node(cx, expr) {
+            return (is_panic(cx, macro_call.def_id)
+                || matches!(
+                    cx.tcx.get_diagnostic_name(macro_call.def_id),
+                    Some(sym::todo_macro | sym::unimplemented_macro | sym::unreachable_macro)
+                ))
+            .then_some(macro_call.span);
+        }
+        expr = match expr.kind {
+            ExprKind::Block(
+                Block {
+                    stmts: [],
+                    expr: Some(inner),
+                    ..
+                }
+                | Block {
+                    stmts:
+                        [
+                            Stmt {
+                                kind: StmtKind::Semi(inner) | StmtKind::Expr(inner),
+                                ..
+                            },
+                        ],
+                    expr: None,
+                    ..
+                },
+                _,
+            ) => inner,
+            _ => return None,
+        };
+    }
+}
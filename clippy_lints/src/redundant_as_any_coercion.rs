@@ -0,0 +1,110 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::ty::is_dyn_any;
+use clippy_utils::{is_self, path_to_local_id};
+use rustc_errors::Applicability;
+use rustc_hir::{Block, Expr, ExprKind, ImplItem, ImplItemKind, Mutability, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, TypeckResults};
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for methods returning `&dyn Any` or `&mut dyn Any`, like the `as_any` method of
+    /// the common `AsAny` trait, which cast or reborrow `self` before returning it.
+    ///
+    /// ### Why is this bad?
+    /// `self` is coerced to `&dyn Any` by the return type, so the cast or reborrow does nothing.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// trait AsAny {
+    ///     fn as_any(&self) -> &dyn Any;
+    /// }
+    ///
+    /// struct Foo;
+    ///
+    /// impl AsAny for Foo {
+    ///     fn as_any(&self) -> &dyn Any {
+    ///         self as &dyn Any
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::any::Any;
+    /// # trait AsAny {
+    /// #     fn as_any(&self) -> &dyn Any;
+    /// # }
+    /// # struct Foo;
+    /// impl AsAny for Foo {
+    ///     fn as_any(&self) -> &dyn Any {
+    ///         self
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub REDUNDANT_AS_ANY_COERCION,
+    style,
+    "casting or reborrowing `self` in a method which returns it as `&dyn Any`"
+}
+declare_lint_pass!(RedundantAsAnyCoercion => [REDUNDANT_AS_ANY_COERCION]);
+
+impl<'tcx> LateLintPass<'tcx> for RedundantAsAnyCoercion {
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'tcx>) {
+        if let ImplItemKind::Fn(_, body_id) = item.kind
+            && let body = cx.tcx.hir_body(body_id)
+            && let [self_param, ..] = body.params
+            && is_self(self_param)
+            && let ExprKind::Block(
+                Block {
+                    stmts: [],
+                    expr: Some(tail),
+                    ..
+                },
+                _,
+            ) = body.value.kind
+            && !tail.span.from_expansion()
+            && let output = cx
+                .tcx
+                .fn_sig(item.owner_id)
+                .instantiate_identity()
+                .skip_binder()
+                .output()
+            && let ty::Ref(_, pointee, _) = *output.kind()
+            && is_dyn_any(cx.tcx, pointee)
+            && let typeck = cx.tcx.typeck_body(body_id)
+            && let Some((kind, slf)) = redundant_coercion(typeck, tail)
+            && path_to_local_id(slf, self_param.pat.hir_id)
+            && typeck.expr_ty(slf).is_ref()
+        {
+            span_lint_and_sugg(
+                cx,
+                REDUNDANT_AS_ANY_COERCION,
+                tail.span,
+                format!("this {kind} of `self` is redundant"),
+                "`self` is coerced to the return type, return it directly",
+                "self".to_owned(),
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+}
+
+/// If the expression is a cast or reborrow of `self`, describes it and returns `self`.
+fn redundant_coercion<'tcx>(
+    typeck: &TypeckResults<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+) -> Option<(&'static str, &'tcx Expr<'tcx>)> {
+    match expr.kind {
+        ExprKind::Cast(slf, _) => Some(("cast", slf)),
+        // A shared reborrow of a shared reference is linted by `borrow_deref_ref`
+        ExprKind::AddrOf(_, mutbl, referent)
+            if let ExprKind::Unary(UnOp::Deref, slf) = referent.kind
+                && (mutbl == Mutability::Mut || typeck.expr_ty(slf).ref_mutability() == Some(Mutability::Mut)) =>
+        {
+            Some(("reborrow", slf))
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,121 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::{any_downcast, deref_chain_len, is_dyn_any, is_type_lang_item};
+use clippy_utils::{get_parent_expr, path_to_local, sym};
+use rustc_ast::LitKind;
+use rustc_hir::{Expr, ExprKind, LangItem, MatchSource, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for downcasts of the values of a map from strings to `Box<dyn Any>`, when the
+    /// value is looked up by a string literal.
+    ///
+    /// ### Why is this bad?
+    /// Each key is expected to hold a value of a certain type, but the compiler can't check
+    /// that. A mistyped key or a change to the type stored under it is only noticed when the
+    /// downcast fails at runtime. A struct with a field for each key, or an enum of the value
+    /// types, is checked by the compiler.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// # use std::collections::HashMap;
+    /// fn count(settings: &HashMap<String, Box<dyn Any>>) -> Option<i32> {
+    ///     settings.get("count").and_then(|v| v.downcast_ref::<i32>()).copied()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// struct Settings {
+    ///     count: Option<i32>,
+    /// }
+    ///
+    /// fn count(settings: &Settings) -> Option<i32> {
+    ///     settings.count
+    /// }
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub STRING_KEYED_ANY_MAP,
+    pedantic,
+    "downcasting a value of a string-keyed map of `dyn Any` looked up by a literal key"
+}
+declare_lint_pass!(StringKeyedAnyMap => [STRING_KEYED_ANY_MAP]);
+
+impl<'tcx> LateLintPass<'tcx> for StringKeyedAnyMap {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let Some((_, recv, target)) = any_downcast(cx, expr)
+            && !expr.span.from_expansion()
+            && let Some(key) = literal_lookup_key(cx, recv)
+        {
+            span_lint_and_help(
+                cx,
+                STRING_KEYED_ANY_MAP,
+                expr.span,
+                format!(
+                    "downcasting the value of the key `{}` to `{target}`",
+                    snippet(cx, key.span, "..")
+                ),
+                None,
+                "the compiler can't check which type each key holds, consider a struct with a field for each key instead",
+            );
+        }
+    }
+}
+
+/// If the receiver of the downcast is the value of a string-keyed map of `dyn Any`, looked up by a
+/// string literal, returns that literal.
+fn literal_lookup_key<'tcx>(cx: &LateContext<'tcx>, mut recv: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    // `map.get("key").unwrap()`
+    if let ExprKind::MethodCall(method, inner, _, _) = recv.kind
+        && matches!(method.ident.name, sym::unwrap | sym::expect)
+    {
+        recv = inner;
+    }
+    // `map.get("key")?`
+    if let ExprKind::Match(scrutinee, _, MatchSource::TryDesugar(_)) = recv.kind
+        && let ExprKind::Call(_, [inner]) = scrutinee.kind
+    {
+        recv = inner;
+    }
+    // `map.get("key").and_then(|value| value.downcast_ref())`
+    if let Some(local_id) = path_to_local(recv)
+        && let Node::Pat(pat) = cx.tcx.hir_node(local_id)
+        && let Node::Param(param) = cx.tcx.parent_hir_node(pat.hir_id)
+        && let Node::Expr(closure) = cx.tcx.parent_hir_node(param.hir_id)
+        && let Some(parent) = get_parent_expr(cx, closure)
+        && let ExprKind::MethodCall(_, inner, args, _) = parent.kind
+        && args.iter().any(|arg| arg.hir_id == closure.hir_id)
+    {
+        recv = inner;
+    }
+
+    let (map, key) = match recv.kind {
+        ExprKind::MethodCall(method, map, [key], _) if matches!(method.ident.name, sym::get | sym::get_mut) => {
+            (map, key)
+        },
+        ExprKind::Index(map, key, _) => (map, key),
+        _ => return None,
+    };
+    let key = match key.kind {
+        ExprKind::AddrOf(_, _, inner) => inner,
+        _ => key,
+    };
+    if let ExprKind::Lit(lit) = key.kind
+        && let LitKind::Str(..) = lit.node
+        && let ty::Adt(adt, args) = *cx.typeck_results().expr_ty_adjusted(map).peel_refs().kind()
+        && matches!(
+            cx.tcx.get_diagnostic_name(adt.did()),
+            Some(sym::HashMap | sym::BTreeMap)
+        )
+        && let key_ty = args.type_at(0).peel_refs()
+        && (key_ty.is_str() || is_type_lang_item(cx, key_ty, LangItem::String))
+        && deref_chain_len(cx, args.type_at(1), |ty| is_dyn_any(cx.tcx, ty)).is_some_and(|len| len > 0)
+    {
+        Some(key)
+    } else {
+        None
+    }
+}
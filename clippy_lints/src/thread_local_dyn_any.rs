@@ -0,0 +1,78 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::macros::macro_backtrace;
+use clippy_utils::sym;
+use clippy_utils::ty::is_dyn_any;
+use rustc_hir::{Item, ItemKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty, TyCtxt};
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `thread_local!` statics whose value type contains a `Box<dyn Any>`.
+    ///
+    /// ### Why is this bad?
+    /// Such a thread local is usually used as an untyped slot shared between unrelated
+    /// call sites. Nothing ties the type that is stored to the type that is later downcast
+    /// to, so a mismatch is only noticed at runtime when the downcast fails.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// # use std::cell::RefCell;
+    /// thread_local! {
+    ///     static SLOT: RefCell<Option<Box<dyn Any>>> = const { RefCell::new(None) };
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::cell::RefCell;
+    /// thread_local! {
+    ///     static SLOT: RefCell<Option<u32>> = const { RefCell::new(None) };
+    /// }
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub THREAD_LOCAL_DYN_ANY,
+    pedantic,
+    "`thread_local!` statics holding a `Box<dyn Any>`"
+}
+declare_lint_pass!(ThreadLocalDynAny => [THREAD_LOCAL_DYN_ANY]);
+
+impl<'tcx> LateLintPass<'tcx> for ThreadLocalDynAny {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        // `thread_local!` expands to a `const` item of type `LocalKey<T>`, named after the
+        // user-written static.
+        if let ItemKind::Const(ident, ..) = item.kind
+            && is_thread_local(cx, item)
+            && let ty::Adt(adt, args) = cx.tcx.type_of(item.owner_id).instantiate_identity().kind()
+            && cx.tcx.is_diagnostic_item(sym::LocalKey, adt.did())
+            && contains_boxed_dyn_any(cx.tcx, args.type_at(0))
+        {
+            span_lint_and_help(
+                cx,
+                THREAD_LOCAL_DYN_ANY,
+                ident.span,
+                "`thread_local!` value contains a `Box<dyn Any>`",
+                None,
+                "consider storing a concrete type, or an enum of the types this slot can hold",
+            );
+        }
+    }
+}
+
+fn is_thread_local(cx: &LateContext<'_>, item: &Item<'_>) -> bool {
+    macro_backtrace(item.span).any(|macro_call| {
+        matches!(
+            cx.tcx.get_diagnostic_name(macro_call.def_id),
+            Some(sym::thread_local_macro)
+        )
+    })
+}
+
+fn contains_boxed_dyn_any<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> bool {
+    ty.walk().any(|arg| {
+        arg.as_type()
+            .and_then(Ty::boxed_ty)
+            .is_some_and(|boxed| is_dyn_any(tcx, boxed))
+    })
+}
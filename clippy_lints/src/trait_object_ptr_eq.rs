@@ -0,0 +1,92 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::{is_path_diagnostic_item, std_or_core};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::impl_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `std::ptr::eq` with pointers to trait objects, such as
+    /// `*const dyn Any` or `&dyn Any`.
+    ///
+    /// ### Why is this bad?
+    /// Pointers to trait objects are wide pointers, and `ptr::eq` compares their vtable
+    /// pointers as well as their addresses. The same type can have several vtables, e.g. one
+    /// per codegen unit, so two pointers to the same value can compare unequal. To check if
+    /// two pointers refer to the same value, only their addresses should be compared.
+    ///
+    /// Clippy's deprecated `vtable_address_comparisons` lint also covered `ptr::eq`, and was
+    /// replaced by rustc's `ambiguous_wide_pointer_comparisons`. That lint takes an explicit
+    /// `ptr::eq` as a request to compare the vtables and only warns about `==`. This lint
+    /// disagrees, as `ptr::eq` is usually written to check if two references point to the same
+    /// value, which is what it does for thin pointers.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// fn same(a: &dyn Any, b: &dyn Any) -> bool {
+    ///     std::ptr::eq(a, b)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::any::Any;
+    /// fn same(a: &dyn Any, b: &dyn Any) -> bool {
+    ///     std::ptr::addr_eq(a, b)
+    /// }
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub TRAIT_OBJECT_PTR_EQ,
+    suspicious,
+    "`ptr::eq` on pointers to trait objects, which also compares vtables"
+}
+
+pub struct TraitObjectPtrEq {
+    msrv: Msrv,
+}
+
+impl TraitObjectPtrEq {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self { msrv: conf.msrv }
+    }
+}
+
+impl_lint_pass!(TraitObjectPtrEq => [TRAIT_OBJECT_PTR_EQ]);
+
+impl<'tcx> LateLintPass<'tcx> for TraitObjectPtrEq {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) {
+        if let ExprKind::Call(func, [_, _]) = e.kind
+            && !e.span.from_expansion()
+            && is_path_diagnostic_item(cx, func, sym::ptr_eq)
+            && let pointee_ty = cx.typeck_results().node_args(func.hir_id).type_at(0)
+            && let ty::Dynamic(..) = pointee_ty.kind()
+        {
+            span_lint_and_then(
+                cx,
+                TRAIT_OBJECT_PTR_EQ,
+                e.span,
+                format!("comparing pointers to `{pointee_ty}` with `ptr::eq`"),
+                |diag| {
+                    diag.note(
+                        "this compares the vtables as well as the addresses, and a type can have several vtables",
+                    );
+                    if let Some(std_or_core) = std_or_core(cx)
+                        && self.msrv.meets(cx, msrvs::PTR_ADDR_EQ)
+                    {
+                        diag.span_suggestion(
+                            func.span,
+                            "to only compare the addresses, use",
+                            format!("{std_or_core}::ptr::addr_eq"),
+                            Applicability::MaybeIncorrect,
+                        );
+                    }
+                },
+            );
+        }
+    }
+}
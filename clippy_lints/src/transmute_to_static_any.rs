@@ -0,0 +1,125 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::expr_or_init;
+use clippy_utils::ty::is_dyn_any;
+use rustc_hir::{Block, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::adjustment::{Adjust, PointerCoercion};
+use rustc_middle::ty::{self, Ty, TypeVisitableExt};
+use rustc_session::declare_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for values which have their lifetime changed by `transmute`, and are then
+    /// coerced to `dyn Any`, e.g. by boxing them into a `Box<dyn Any>`.
+    ///
+    /// ### Why restrict this?
+    /// `dyn Any` can only hold `'static` values, so the transmute extends the lifetime to
+    /// `'static`. Type erasure hides that the value still borrows from something that may be
+    /// dropped, and downcasting it afterwards is almost always undefined behavior.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::Any;
+    /// # use std::mem;
+    /// fn erase(name: &str) -> Box<dyn Any> {
+    ///     let name: &'static str = unsafe { mem::transmute(name) };
+    ///     Box::new(name)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::any::Any;
+    /// fn erase(name: &str) -> Box<dyn Any> {
+    ///     Box::new(name.to_owned())
+    /// }
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub TRANSMUTE_TO_STATIC_ANY,
+    restriction,
+    "extending a lifetime to `'static` with `transmute` to coerce a value to `dyn Any`"
+}
+declare_lint_pass!(TransmuteToStaticAny => [TRANSMUTE_TO_STATIC_ANY]);
+
+impl<'tcx> LateLintPass<'tcx> for TransmuteToStaticAny {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if !expr.span.from_expansion()
+            && cx.typeck_results().expr_adjustments(expr).iter().any(|adjustment| {
+                matches!(adjustment.kind, Adjust::Pointer(PointerCoercion::Unsize))
+                    && pointee(adjustment.target).is_some_and(|ty| is_dyn_any(cx.tcx, ty))
+            })
+            && let Some(transmute) = lifetime_transmute(cx, boxed_value(cx, expr))
+        {
+            span_lint_and_then(
+                cx,
+                TRANSMUTE_TO_STATIC_ANY,
+                expr.span,
+                "coercing a value with a transmuted lifetime to `dyn Any`",
+                |diag| {
+                    diag.span_note(transmute.span, "the lifetime is extended to `'static` here");
+                    diag.help("the `dyn Any` can outlive the borrowed data, consider storing an owned value instead");
+                },
+            );
+        }
+    }
+}
+
+/// Returns the type a `dyn Any` is stored behind in a reference, `Box`, `Rc` or `Arc`.
+fn pointee(ty: Ty<'_>) -> Option<Ty<'_>> {
+    match *ty.kind() {
+        ty::Ref(_, ty, _) | ty::RawPtr(ty, _) => Some(ty),
+        ty::Adt(_, args) => args.types().next(),
+        _ => None,
+    }
+}
+
+/// If the expression is a call to `Box::new`, `Rc::new` or `Arc::new`, returns the boxed value.
+/// Otherwise returns the expression itself.
+fn boxed_value<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    if let ExprKind::Call(func, [arg]) = expr.kind
+        && let ExprKind::Path(ref qpath) = func.kind
+        && let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id()
+        && cx.tcx.item_name(def_id) == sym::new
+        && let Some(adt) = cx.typeck_results().expr_ty(expr).ty_adt_def()
+        && (adt.is_box() || matches!(cx.tcx.get_diagnostic_name(adt.did()), Some(sym::Rc | sym::Arc)))
+    {
+        arg
+    } else {
+        expr
+    }
+}
+
+/// If the expression is, or is a local initialized with, a `transmute` between types that only
+/// differ in their lifetimes, returns that `transmute` call.
+fn lifetime_transmute<'tcx>(cx: &LateContext<'tcx>, mut expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    loop {
+        expr = expr_or_init(cx, expr);
+        // Look through `unsafe` blocks
+        match expr.kind {
+            ExprKind::Block(
+                Block {
+                    stmts: [],
+                    expr: Some(inner),
+                    ..
+                },
+                _,
+            ) => expr = inner,
+            _ => break,
+        }
+    }
+
+    if let ExprKind::Call(func, [arg]) = expr.kind
+        && let ExprKind::Path(ref qpath) = func.kind
+        && let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id()
+        && cx.tcx.is_diagnostic_item(sym::transmute, def_id)
+    {
+        let typeck = cx.typeck_results();
+        let from_ty = typeck.expr_ty_adjusted(arg);
+        let to_ty = typeck.expr_ty(expr);
+        // Lifetimes are erased after type checking, so types which only differ in their
+        // lifetimes compare equal
+        (from_ty == to_ty && from_ty.has_erased_regions()).then_some(expr)
+    } else {
+        None
+    }
+}
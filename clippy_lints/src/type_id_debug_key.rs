@@ -0,0 +1,156 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::{FormatArgsStorage, find_format_arg_expr, root_macro_call_first_node};
+use clippy_utils::ty::is_dyn_any;
+use clippy_utils::{paths, sym};
+use rustc_ast::{FormatArgsPiece, FormatTrait};
+use rustc_hir::{Expr, ExprKind, Node, PatKind, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::impl_lint_pass;
+use rustc_span::{Span, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `TypeId`s and `dyn Any` values formatted with `Debug` by `format!`, when the
+    /// resulting string is used as a key: stored in a binding or field named like `key` or
+    /// `hash`, or used as the key of a map or set.
+    ///
+    /// ### Why restrict this?
+    /// The `Debug` output of a `TypeId` is not guaranteed to be stable between compiler
+    /// versions, or even between builds, and a `dyn Any` is only formatted as `Any { .. }`.
+    /// Keys built from them may not match persisted keys, or may collide.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::any::TypeId;
+    /// # use std::collections::HashMap;
+    /// fn insert<T: 'static>(cache: &mut HashMap<String, u32>, value: u32) {
+    ///     cache.insert(format!("{:?}", TypeId::of::<T>()), value);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::any::TypeId;
+    /// # use std::collections::HashMap;
+    /// fn insert<T: 'static>(cache: &mut HashMap<TypeId, u32>, value: u32) {
+    ///     cache.insert(TypeId::of::<T>(), value);
+    /// }
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub TYPE_ID_DEBUG_KEY,
+    restriction,
+    "using the `Debug` output of a `TypeId` or `dyn Any` as a key"
+}
+
+pub struct TypeIdDebugKey {
+    format_args: FormatArgsStorage,
+}
+
+impl TypeIdDebugKey {
+    pub fn new(format_args: FormatArgsStorage) -> Self {
+        Self { format_args }
+    }
+}
+
+impl_lint_pass!(TypeIdDebugKey => [TYPE_ID_DEBUG_KEY]);
+
+impl<'tcx> LateLintPass<'tcx> for TypeIdDebugKey {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let Some(macro_call) = root_macro_call_first_node(cx, expr)
+            && cx.tcx.is_diagnostic_item(sym::format_macro, macro_call.def_id)
+            && let Some(format_args) = self.format_args.get(cx, expr, macro_call.expn)
+            && let Some(key_span) = used_as_key(cx, expr)
+        {
+            for piece in &format_args.template {
+                if let FormatArgsPiece::Placeholder(placeholder) = piece
+                    && placeholder.format_trait == FormatTrait::Debug
+                    && let Ok(index) = placeholder.argument.index
+                    && let Some(arg) = format_args.arguments.all_args().get(index)
+                    && let Some(arg_expr) = find_format_arg_expr(expr, arg)
+                    && let ty = cx.typeck_results().expr_ty(arg_expr).peel_refs()
+                    && let Some(name) = if paths::ANY_TYPE_ID.matches_ty(cx, ty) {
+                        Some("TypeId")
+                    } else if is_dyn_any(cx.tcx, ty) || ty.boxed_ty().is_some_and(|ty| is_dyn_any(cx.tcx, ty)) {
+                        Some("dyn Any")
+                    } else {
+                        None
+                    }
+                {
+                    span_lint_and_then(
+                        cx,
+                        TYPE_ID_DEBUG_KEY,
+                        arg_expr.span,
+                        format!("the `Debug` output of a `{name}` is used in a key"),
+                        |diag| {
+                            diag.span_note(key_span, "the formatted string is used as a key here");
+                            diag.note(if name == "TypeId" {
+                                "the `Debug` output of a `TypeId` is not guaranteed to be stable"
+                            } else {
+                                "a `dyn Any` is formatted as `Any { .. }`, whatever its value"
+                            });
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// If the `format!` call is stored in a binding, field or assignee named like a key, or is used
+/// as the key of a map or set, returns the span of that use.
+fn used_as_key(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<Span> {
+    let mut hir_id = expr.hir_id;
+    let mut parent = cx.tcx.parent_hir_node(hir_id);
+    if let Node::Expr(
+        e @ Expr {
+            kind: ExprKind::AddrOf(..),
+            ..
+        },
+    ) = parent
+    {
+        hir_id = e.hir_id;
+        parent = cx.tcx.parent_hir_node(hir_id);
+    }
+    match parent {
+        Node::LetStmt(local) if let PatKind::Binding(_, _, ident, None) = local.pat.kind => {
+            is_key_name(ident.name).then_some(local.pat.span)
+        },
+        Node::ExprField(field) => is_key_name(field.ident.name).then_some(field.ident.span),
+        Node::Expr(parent) => match parent.kind {
+            ExprKind::Assign(lhs, rhs, _) if rhs.hir_id == hir_id => {
+                let name = match lhs.kind {
+                    ExprKind::Path(QPath::Resolved(None, path)) => path.segments.last()?.ident,
+                    ExprKind::Field(_, ident) => ident,
+                    _ => return None,
+                };
+                is_key_name(name.name).then_some(lhs.span)
+            },
+            ExprKind::MethodCall(method, recv, [key, ..], _)
+                if key.hir_id == hir_id
+                    && matches!(
+                        method.ident.name,
+                        sym::insert
+                            | sym::entry
+                            | sym::get
+                            | sym::get_mut
+                            | sym::contains_key
+                            | sym::contains
+                            | sym::remove
+                    )
+                    && let Some(adt) = cx.typeck_results().expr_ty_adjusted(recv).peel_refs().ty_adt_def()
+                    && matches!(
+                        cx.tcx.get_diagnostic_name(adt.did()),
+                        Some(sym::HashMap | sym::BTreeMap | sym::HashSet | sym::BTreeSet)
+                    ) =>
+            {
+                Some(parent.span)
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_key_name(name: Symbol) -> bool {
+    let name = name.as_str().to_ascii_lowercase();
+    name.contains("key") || name.contains("hash")
+}
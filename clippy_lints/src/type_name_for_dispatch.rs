@@ -0,0 +1,92 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::paths;
+use rustc_hir::{BinOpKind, Expr, ExprKind, MatchSource, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for the results of `std::any::type_name` and `std::any::type_name_of_val` being
+    /// compared with `==` or `!=`, or matched against string literals.
+    ///
+    /// ### Why is this bad?
+    /// Type names are only meant for diagnostics. They are not guaranteed to be unique, and may
+    /// change between compiler versions, so using them to identify a type at runtime is
+    /// unreliable. `TypeId` is guaranteed to identify a type.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn is_u32<T>() -> bool {
+    ///     std::any::type_name::<T>() == "u32"
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// use std::any::TypeId;
+    ///
+    /// fn is_u32<T: 'static>() -> bool {
+    ///     TypeId::of::<T>() == TypeId::of::<u32>()
+    /// }
+    /// ```
+    #[clippy::version = "1.89.0"]
+    pub TYPE_NAME_FOR_DISPATCH,
+    correctness,
+    "using `type_name` to identify a type at runtime"
+}
+declare_lint_pass!(TypeNameForDispatch => [TYPE_NAME_FOR_DISPATCH]);
+
+impl<'tcx> LateLintPass<'tcx> for TypeNameForDispatch {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        match expr.kind {
+            ExprKind::Binary(op, left, right)
+                if matches!(op.node, BinOpKind::Eq | BinOpKind::Ne)
+                    && (is_type_name_call(cx, left) || is_type_name_call(cx, right)) =>
+            {
+                lint(cx, expr, "comparing type names to identify a type");
+            },
+            ExprKind::Match(scrutinee, arms, MatchSource::Normal)
+                if is_type_name_call(cx, scrutinee)
+                    && arms.iter().any(|arm| {
+                        let mut has_lit = false;
+                        arm.pat
+                            .walk_always(|pat| has_lit |= matches!(pat.kind, PatKind::Expr(_)));
+                        has_lit
+                    }) =>
+            {
+                lint(cx, scrutinee, "matching on a type name to identify a type");
+            },
+            _ => {},
+        }
+    }
+}
+
+fn lint(cx: &LateContext<'_>, expr: &Expr<'_>, msg: &'static str) {
+    span_lint_and_help(
+        cx,
+        TYPE_NAME_FOR_DISPATCH,
+        expr.span,
+        msg,
+        None,
+        "type names are not guaranteed to be unique or stable, compare `TypeId`s instead",
+    );
+}
+
+/// Checks if the expression is a call to `type_name` or `type_name_of_val`, possibly behind
+/// references.
+fn is_type_name_call(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let mut expr = expr;
+    while let ExprKind::AddrOf(_, _, inner) = expr.kind {
+        expr = inner;
+    }
+    if let ExprKind::Call(func, _) = expr.kind
+        && let ExprKind::Path(ref qpath) = func.kind
+        && let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id()
+    {
+        paths::ANY_TYPE_NAME.matches(cx, def_id) || paths::ANY_TYPE_NAME_OF_VAL.matches(cx, def_id)
+    } else {
+        false
+    }
+}
@@ -32,7 +32,7 @@ msrv_aliases! {
     1,81,0 { LINT_REASONS_STABILIZATION, ERROR_IN_CORE, EXPLICIT_SELF_TYPE_ELISION, DURATION_ABS_DIFF }
     1,80,0 { BOX_INTO_ITER, LAZY_CELL }
     1,77,0 { C_STR_LITERALS }
-    1,76,0 { PTR_FROM_REF, OPTION_RESULT_INSPECT }
+    1,76,0 { PTR_FROM_REF, OPTION_RESULT_INSPECT, PTR_ADDR_EQ }
     1,75,0 { OPTION_AS_SLICE }
     1,74,0 { REPR_RUST, IO_ERROR_OTHER }
     1,73,0 { DIV_CEIL }
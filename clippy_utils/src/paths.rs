@@ -128,11 +128,15 @@ path_macros! {
 
 // Paths in `core`/`alloc`/`std`. This should be avoided and cleaned up by adding diagnostic items.
 pub static ALIGN_OF: PathLookup = value_path!(core::mem::align_of);
+pub static ANY_TYPE_ID: PathLookup = type_path!(core::any::TypeId);
+pub static ANY_TYPE_NAME: PathLookup = value_path!(core::any::type_name);
+pub static ANY_TYPE_NAME_OF_VAL: PathLookup = value_path!(core::any::type_name_of_val);
 pub static CHAR_TO_DIGIT: PathLookup = value_path!(char::to_digit);
 pub static CONCAT: PathLookup = macro_path!(core::concat);
 pub static IO_ERROR_NEW: PathLookup = value_path!(std::io::Error::new);
 pub static IO_ERRORKIND_OTHER_CTOR: PathLookup = value_path!(std::io::ErrorKind::Other);
 pub static ITER_STEP: PathLookup = type_path!(core::iter::Step);
+pub static MEM_TAKE: PathLookup = value_path!(core::mem::take);
 pub static SLICE_FROM_REF: PathLookup = value_path!(core::slice::from_ref);
 
 // Paths in external crates
@@ -556,6 +556,52 @@ pub fn make_binop(op: ast::BinOpKind, lhs: &Sugg<'_>, rhs: &Sugg<'_>) -> Sugg<'s
     make_assoc(AssocOp::Binary(op), lhs, rhs)
 }
 
+/// Builds the parts of a multipart suggestion replacing `expr`, an expression of type `&T`, with
+/// a reference to the value found `depth` dereferences into `T`. E.g. `&x` with a `depth` of 1
+/// becomes `&*x`, and `x` becomes `&**x`.
+/// The new borrow is mutable if `expr` is coerced to a mutable reference.
+///
+/// Lints which need to edit other spans as well can extend the returned parts.
+pub fn render_deref_suggestion(
+    cx: &LateContext<'_>,
+    expr: &hir::Expr<'_>,
+    depth: usize,
+    applicability: &mut Applicability,
+) -> Vec<(Span, String)> {
+    let (mut target_expr, mut deref_count) = match expr.kind {
+        // If `expr` was already an `&` expression, skip `*&` in the suggestion
+        ExprKind::AddrOf(_, _, referent) => (referent, depth),
+        _ => (expr, depth + 1),
+    };
+    // Rewrite the existing `*`s along with the new ones, so they're spaced the same way
+    while let ExprKind::Unary(hir::UnOp::Deref, inner) = target_expr.kind
+        && inner.span.eq_ctxt(expr.span)
+    {
+        target_expr = inner;
+        deref_count += 1;
+    }
+    // Prefix operators bind looser than method calls, field accesses and indexing, so only wrap
+    // the referent in parentheses if it could otherwise be split by the `*`s.
+    let sugg = Sugg::hir_with_context(cx, target_expr, expr.span.ctxt(), "x", applicability).maybe_paren();
+    // Keep a mutable borrow if the coercion is to `&mut dyn Any`
+    let borrow = match cx.typeck_results().expr_ty_adjusted(expr).kind() {
+        ty::Ref(_, _, Mutability::Mut) => "&mut ",
+        _ => "&",
+    };
+    let sugg = format!("{borrow}{}{sugg}", "*".repeat(deref_count));
+    // The span of a parenthesized expression includes the parentheses, which are replaced too, so
+    // add them back if the borrow would otherwise be split by a method call or field access.
+    let sugg = if let Some(parent) = get_parent_expr_for_hir(cx, expr.hir_id)
+        && let ExprKind::MethodCall(_, base, ..) | ExprKind::Field(base, _) | ExprKind::Index(base, ..) = parent.kind
+        && base.hir_id == expr.hir_id
+    {
+        format!("({sugg})")
+    } else {
+        sugg
+    };
+    vec![(expr.span, sugg)]
+}
+
 #[derive(PartialEq, Eq, Clone, Copy)]
 /// Operator associativity.
 enum Associativity {
@@ -69,6 +69,7 @@ generate! {
     Symbol,
     SyntaxContext,
     TBD,
+    TypeId,
     UpperExp,
     UpperHex,
     V4,
@@ -116,6 +117,7 @@ generate! {
     collect,
     const_ptr,
     contains,
+    contains_key,
     copied,
     copy_from,
     copy_from_nonoverlapping,
@@ -129,9 +131,13 @@ generate! {
     de,
     diagnostics,
     disallowed_types,
+    downcast,
+    downcast_mut,
+    downcast_ref,
     drain,
     dump,
     ends_with,
+    entry,
     enum_glob_use,
     enumerate,
     err,
@@ -268,6 +274,7 @@ generate! {
     redundant_pub_crate,
     regex,
     rem_euclid,
+    remove,
     repeat,
     replace,
     replacen,
@@ -349,6 +356,7 @@ generate! {
     trim_start,
     trim_start_matches,
     truncate,
+    type_name_of_val,
     unreachable_pub,
     unsafe_removed_from_name,
     unused,
@@ -30,7 +30,7 @@ use rustc_trait_selection::traits::query::normalize::QueryNormalizeExt;
 use rustc_trait_selection::traits::{Obligation, ObligationCause};
 use std::assert_matches::debug_assert_matches;
 use std::collections::hash_map::Entry;
-use std::iter;
+use std::{fmt, iter};
 
 use crate::path_res;
 use crate::paths::{PathNS, lookup_path_str};
@@ -1284,6 +1284,12 @@ pub fn deref_chain<'cx, 'tcx>(cx: &'cx LateContext<'tcx>, ty: Ty<'tcx>) -> impl
     })
 }
 
+/// Returns the number of derefs needed to get from `ty` to the first type in its deref chain which
+/// satisfies `stop`, or `None` if no type in the chain does.
+pub fn deref_chain_len<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>, stop: impl Fn(Ty<'tcx>) -> bool) -> Option<usize> {
+    deref_chain(cx, ty).position(stop)
+}
+
 /// Checks if a Ty<'_> has some inherent method Symbol.
 ///
 /// This does not look for impls in the type's `Deref::Target` type.
@@ -1391,3 +1397,91 @@ pub fn get_field_idx_by_name(ty: Ty<'_>, name: Symbol) -> Option<usize> {
         _ => None,
     }
 }
+
+/// The auto traits of a `dyn Any` trait object which are relevant to lints, see
+/// [`dyn_any_auto_traits`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AutoTraitSet {
+    pub send: bool,
+    pub sync: bool,
+}
+
+impl AutoTraitSet {
+    pub fn is_empty(self) -> bool {
+        !self.send && !self.sync
+    }
+}
+
+/// Formats the set as the trait object type it was taken from, e.g. `dyn Any + Send`.
+impl fmt::Display for AutoTraitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("dyn Any")?;
+        if self.send {
+            f.write_str(" + Send")?;
+        }
+        if self.sync {
+            f.write_str(" + Sync")?;
+        }
+        Ok(())
+    }
+}
+
+/// If the type is a trait object whose principal trait is `core::any::Any`, e.g. `dyn Any` or
+/// `dyn Any + Send`, returns the `Send` and `Sync` auto traits it includes.
+pub fn dyn_any_auto_traits(tcx: TyCtxt<'_>, ty: Ty<'_>) -> Option<AutoTraitSet> {
+    let ty::Dynamic(preds, ..) = ty.kind() else {
+        return None;
+    };
+    if !preds
+        .principal_def_id()
+        .is_some_and(|did| tcx.is_diagnostic_item(sym::Any, did))
+    {
+        return None;
+    }
+    let mut auto_traits = AutoTraitSet::default();
+    for did in preds.auto_traits() {
+        if tcx.is_diagnostic_item(sym::Send, did) {
+            auto_traits.send = true;
+        } else if Some(did) == tcx.lang_items().sync_trait() {
+            auto_traits.sync = true;
+        }
+    }
+    Some(auto_traits)
+}
+
+/// Checks if the type is a trait object whose principal trait is `core::any::Any`, e.g.
+/// `dyn Any` or `dyn Any + Send`.
+pub fn is_dyn_any(tcx: TyCtxt<'_>, ty: Ty<'_>) -> bool {
+    dyn_any_auto_traits(tcx, ty).is_some()
+}
+
+/// Checks if the type is or contains a trait object type, e.g. `dyn Debug` or `Box<dyn Debug>`.
+pub fn contains_dyn_trait(ty: Ty<'_>) -> bool {
+    ty.walk()
+        .any(|arg| arg.as_type().is_some_and(|ty| matches!(ty.kind(), ty::Dynamic(..))))
+}
+
+/// If the expression calls one of the type checking methods of `dyn Any` or `Box<dyn Any>`
+/// (`is`, `downcast_ref`, `downcast_mut` or `downcast`), returns the name of the method, its
+/// receiver and the type being checked for.
+pub fn any_downcast<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+) -> Option<(Symbol, &'tcx Expr<'tcx>, Ty<'tcx>)> {
+    if let hir::ExprKind::MethodCall(path, recv, [], _) = expr.kind
+        && matches!(
+            path.ident.name,
+            sym::is | crate::sym::downcast | crate::sym::downcast_mut | crate::sym::downcast_ref
+        )
+        && let Some(method_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
+        && let Some(impl_id) = cx.tcx.impl_of_method(method_id)
+        && cx.tcx.trait_id_of_impl(impl_id).is_none()
+        && let self_ty = cx.tcx.type_of(impl_id).instantiate_identity()
+        && (is_dyn_any(cx.tcx, self_ty) || self_ty.boxed_ty().is_some_and(|ty| is_dyn_any(cx.tcx, ty)))
+        && let Some(target) = cx.typeck_results().node_args(expr.hir_id).types().last()
+    {
+        Some((path.ident.name, recv, target))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,38 @@
+#![feature(rustc_private)]
+#![warn(rust_2018_idioms, unused_lifetimes)]
+
+// Checks that the metadata the lint list and `cargo clippy --explain` are built from is
+// collected for a lint.
+
+extern crate rustc_lint;
+
+use clippy_lints::LintInfo;
+use clippy_lints::declared_lints::LINTS;
+use rustc_lint::Level;
+
+fn lint_info(name: &str) -> &'static LintInfo {
+    LINTS
+        .iter()
+        .copied()
+        .find(|info| info.name_lower() == name)
+        .unwrap_or_else(|| panic!("`{name}` is not in `declared_lints::LINTS`"))
+}
+
+#[test]
+fn coerce_container_to_any() {
+    let info = lint_info("coerce_container_to_any");
+    assert_eq!(info.lint.name, "clippy::COERCE_CONTAINER_TO_ANY");
+    assert_eq!(info.category_str(), "suspicious");
+    assert_eq!(info.lint.default_level, Level::Warn);
+    assert_eq!(info.version, Some("1.88.0"));
+    assert!(
+        info.location
+            .starts_with("clippy_lints/src/coerce_container_to_any.rs#"),
+        "unexpected location `{}`",
+        info.location,
+    );
+    for section in ["### What it does", "### Why is this bad?", "### Example"] {
+        assert!(info.explanation.contains(section), "the explanation has no `{section}`");
+    }
+    assert_eq!(clippy_lints::explain("coerce_container_to_any"), 0);
+}
@@ -0,0 +1,15 @@
+#![warn(clippy::any_props_bag)]
+
+use std::any::Any;
+
+// All `dyn Any` parameters are linted with an empty list of names
+pub fn store(value: Box<dyn Any>, _: &dyn Any) {}
+//~^ any_props_bag
+//~| any_props_bag
+
+pub fn render(props: &dyn Any, name: &str) {}
+//~^ any_props_bag
+
+fn private(value: Box<dyn Any>) {}
+
+fn main() {}
@@ -0,0 +1,15 @@
+#![warn(clippy::coerce_container_to_any)]
+
+use std::any::Any;
+
+fn main() {
+    let x: Box<dyn Any> = Box::new(());
+    let _: &dyn Any = &x;
+    //~^ coerce_container_to_any
+
+    let double: Box<Box<dyn Any>> = Box::new(Box::new(()));
+    f(&double);
+    //~^ coerce_container_to_any
+}
+
+fn f(_: &dyn Any) {}
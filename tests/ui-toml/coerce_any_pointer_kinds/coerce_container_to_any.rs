@@ -0,0 +1,50 @@
+//@revisions: box_only all
+//@[box_only] rustc-env:CLIPPY_CONF_DIR=tests/ui-toml/coerce_any_pointer_kinds/box_only
+//@[all] rustc-env:CLIPPY_CONF_DIR=tests/ui-toml/coerce_any_pointer_kinds/all
+#![warn(clippy::coerce_container_to_any)]
+
+use std::any::Any;
+use std::ops::Deref;
+use std::rc::Rc;
+use std::sync::Arc;
+
+struct Wrapper(Box<dyn Any>);
+
+impl Deref for Wrapper {
+    type Target = Box<dyn Any>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+fn main() {
+    let boxed: Box<dyn Any> = Box::new(());
+    f(&boxed);
+    //~^ coerce_container_to_any
+    let ref_boxed = &boxed;
+    f(ref_boxed);
+    //~^ coerce_container_to_any
+
+    let rc: Rc<dyn Any> = Rc::new(());
+    f(&rc);
+    //~[all]^ coerce_container_to_any
+    let arc: Arc<dyn Any> = Arc::new(());
+    f(&arc);
+    //~[all]^ coerce_container_to_any
+
+    // The outermost container decides
+    let rc_box: Rc<Box<dyn Any>> = Rc::new(Box::new(()));
+    f(&rc_box);
+    //~[all]^ coerce_container_to_any
+    let box_rc: Box<Rc<dyn Any>> = Box::new(Rc::new(()));
+    f(&box_rc);
+    //~^ coerce_container_to_any
+
+    // Other containers are always linted
+    let wrapper = Wrapper(Box::new(()));
+    f(&wrapper);
+    //~^ coerce_container_to_any
+}
+
+fn f(_: &dyn Any) {}
@@ -0,0 +1,57 @@
+#![warn(clippy::any_downcast_in_drop)]
+
+use std::any::Any;
+
+struct Unwrapped {
+    handle: Box<dyn Any>,
+}
+
+impl Drop for Unwrapped {
+    fn drop(&mut self) {
+        let handle = self.handle.downcast_mut::<Vec<u8>>().unwrap();
+        //~^ any_downcast_in_drop
+        handle.clear();
+    }
+}
+
+struct Matched {
+    handle: Box<dyn Any + Send>,
+}
+
+impl Drop for Matched {
+    fn drop(&mut self) {
+        match self.handle.downcast_ref::<String>() {
+            //~^ any_downcast_in_drop
+            Some(s) => println!("dropping {s}"),
+            None => println!("dropping something else"),
+        }
+        let log = |any: &dyn Any| any.downcast_ref::<u32>().copied();
+        //~^ any_downcast_in_drop
+        log(&*self.handle);
+    }
+}
+
+struct TypeCheck {
+    handle: Box<dyn Any>,
+}
+
+// Only checking the type can't go wrong
+impl Drop for TypeCheck {
+    fn drop(&mut self) {
+        if self.handle.is::<String>() {
+            println!("dropping a string");
+        }
+    }
+}
+
+struct NotDrop {
+    handle: Box<dyn Any>,
+}
+
+impl NotDrop {
+    fn drop(&mut self) {
+        let _ = self.handle.downcast_mut::<Vec<u8>>().unwrap();
+    }
+}
+
+fn main() {}
@@ -0,0 +1,60 @@
+#![warn(clippy::any_in_error_position)]
+
+use std::any::Any;
+use std::error::Error;
+
+fn any_err() -> Result<u32, Box<dyn Any>> {
+    //~^ any_in_error_position
+    Err(Box::new(()))
+}
+
+pub fn any_send_err() -> Result<(), Box<dyn Any + Send>> {
+    //~^ any_in_error_position
+    Ok(())
+}
+
+type AnyResult<T> = Result<T, Box<dyn Any>>;
+
+fn aliased() -> AnyResult<()> {
+    //~^ any_in_error_position
+    Ok(())
+}
+
+struct S;
+
+impl S {
+    fn method(&self) -> Result<(), Box<dyn Any>> {
+        //~^ any_in_error_position
+        Ok(())
+    }
+}
+
+trait T {
+    fn required() -> Result<(), Box<dyn Any>>;
+    //~^ any_in_error_position
+}
+
+// Trait implementations can't change the signature
+impl T for S {
+    fn required() -> Result<(), Box<dyn Any>> {
+        Ok(())
+    }
+}
+
+fn error_err() -> Result<(), Box<dyn Error>> {
+    Ok(())
+}
+
+fn any_ok() -> Result<Box<dyn Any>, ()> {
+    Ok(Box::new(()))
+}
+
+fn borrowed_any(x: &dyn Any) -> Result<(), &dyn Any> {
+    Err(x)
+}
+
+fn nested_any() -> Result<(), Vec<Box<dyn Any>>> {
+    Ok(())
+}
+
+fn main() {}
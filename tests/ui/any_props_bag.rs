@@ -0,0 +1,48 @@
+#![warn(clippy::any_props_bag)]
+
+use std::any::Any;
+
+pub fn render(_name: &str, props: &dyn Any) {}
+//~^ any_props_bag
+
+pub fn spawn(extra: Box<dyn Any + Send>) {}
+//~^ any_props_bag
+
+pub fn call(_ctx: &mut dyn Any, _data: Box<dyn Any>) {}
+//~^ any_props_bag
+//~| any_props_bag
+
+// Other names are not linted with the default configuration
+pub fn store(value: Box<dyn Any>) {}
+
+// Neither are private functions
+fn private(props: &dyn Any) {}
+
+pub struct Widget;
+
+impl Widget {
+    pub fn new(props: &dyn Any) -> Self {
+        //~^ any_props_bag
+        Self
+    }
+}
+
+pub trait Component {
+    fn update(&mut self, props: &dyn Any) {}
+    //~^ any_props_bag
+}
+
+// The signature is dictated by the trait
+impl Component for Widget {
+    fn update(&mut self, props: &dyn Any) {}
+}
+
+mod private_mod {
+    use std::any::Any;
+
+    pub fn render(props: &dyn Any) {}
+}
+
+fn main() {
+    let _ = |props: &dyn Any| {};
+}
@@ -0,0 +1,42 @@
+#![warn(clippy::box_any_round_trip)]
+
+use std::any::Any;
+use std::thread;
+
+fn main() {
+    let payload: Box<dyn Any + Send> = Box::new(42u32);
+    //~^ box_any_round_trip
+    thread::spawn(move || {
+        let n = payload.downcast::<u32>().unwrap();
+        println!("{n}");
+    });
+
+    let value = Box::new(String::new()) as Box<dyn Any>;
+    //~^ box_any_round_trip
+    if value.is::<String>() {
+        println!("{:?}", value.downcast_ref::<String>());
+    }
+
+    let run = {
+        let state: Box<dyn Any> = Box::new(vec![1u8]);
+        //~^ box_any_round_trip
+        move || state.downcast_ref::<Vec<u8>>().map(Vec::len)
+    };
+    run();
+
+    // Used as a `dyn Any`
+    let erased: Box<dyn Any> = Box::new(1u8);
+    let _ = erased.downcast_ref::<u8>();
+    take(erased);
+
+    // Not downcast at all
+    let unused: Box<dyn Any> = Box::new(1u8);
+    take(unused);
+
+    // Mutable locals may be reassigned
+    let mut reassigned: Box<dyn Any> = Box::new(1u8);
+    reassigned = Box::new(2u8);
+    let _ = reassigned.downcast_ref::<u8>();
+}
+
+fn take(_: Box<dyn Any>) {}
@@ -0,0 +1,42 @@
+#![warn(clippy::box_downcast_ok)]
+#![allow(clippy::match_result_ok)]
+
+use std::any::Any;
+use std::rc::Rc;
+
+fn take<T: Any>(value: Box<dyn Any>) -> Option<Box<T>> {
+    value.downcast::<T>().ok()
+    //~^ box_downcast_ok
+}
+
+fn take_send(value: Box<dyn Any + Send>) -> Option<u32> {
+    value.downcast::<u32>().ok().map(|n| *n)
+    //~^ box_downcast_ok
+}
+
+fn inferred(value: Box<dyn Any>) {
+    if let Some(n) = value.downcast().ok() {
+        //~^ box_downcast_ok
+        let _: Box<u32> = n;
+    }
+}
+
+// The box is handed back on failure
+fn retry(value: Box<dyn Any>) -> Option<u32> {
+    match value.downcast::<u32>() {
+        Ok(n) => Some(*n),
+        Err(value) => value.downcast::<u8>().ok().map(|n| u32::from(*n)),
+        //~^ box_downcast_ok
+    }
+}
+
+fn give_back<T: Any>(value: Box<dyn Any>) -> Result<Box<T>, Box<dyn Any>> {
+    value.downcast::<T>()
+}
+
+// Other owners of the value may still exist
+fn rc(value: Rc<dyn Any>) -> Option<Rc<u32>> {
+    value.downcast::<u32>().ok()
+}
+
+fn main() {}
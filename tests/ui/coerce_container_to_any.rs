@@ -1,6 +1,14 @@
+//@aux-build:proc_macros.rs
 #![warn(clippy::coerce_container_to_any)]
+#![allow(clippy::redundant_any_cast)]
+
+extern crate proc_macros;
+use proc_macros::external;
 
 use std::any::Any;
+use std::borrow::Cow;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
 
 fn main() {
     let x: Box<dyn Any> = Box::new(());
@@ -12,6 +20,10 @@ fn main() {
     f(ref_x);
     //~^ coerce_container_to_any
 
+    let leaked: &'static Box<dyn Any> = Box::leak(Box::new(Box::new(()) as Box<dyn Any>));
+    f(&leaked);
+    //~^ coerce_container_to_any
+
     let _: &dyn Any = &x;
     //~^ coerce_container_to_any
 
@@ -21,6 +33,341 @@ fn main() {
     f(&**ref_x);
     f(&*x);
     let _: &dyn Any = &*x;
+
+    let double: Box<Box<dyn Any>> = Box::new(Box::new(()));
+    let ref_double = &double;
+    let wrapper = Wrapper {
+        inner: Box::new(Box::new(())),
+    };
+
+    f(&get_double());
+    //~^ coerce_container_to_any
+
+    f(&wrapper.inner);
+    //~^ coerce_container_to_any
+
+    f(&get_wrapper().inner);
+    //~^ coerce_container_to_any
+
+    f(&**ref_double);
+    //~^ coerce_container_to_any
+
+    f(&if cond() { get_double() } else { double });
+    //~^ coerce_container_to_any
+
+    f(&**get_double());
+
+    f(&{ get_double() });
+    //~^ coerce_container_to_any
+
+    f(&get_wrapper().inner as &dyn Any);
+    //~^ coerce_container_to_any
+
+    // Indexing binds tighter than `*`
+    let boxes: Vec<Box<dyn Any>> = vec![Box::new(())];
+    let wrappers = [get_wrapper()];
+    f(&boxes[0]);
+    //~^ coerce_container_to_any
+    f(&wrappers[0].inner);
+    //~^ coerce_container_to_any
+    f(&*boxes[0]);
+
+    // The elements of `vec![]` and array literals are user-written, so they are still linted
+    let _: Vec<&dyn Any> = vec![&x, &*x];
+    //~^ coerce_container_to_any
+    let _: [&dyn Any; 2] = [&x, &*x];
+    //~^ coerce_container_to_any
+
+    // Raw pointers are not dereferenced by the suggestion, but an explicit deref of one is fine
+    let ptr: *const Box<dyn Any> = &x;
+    f(&ptr);
+    unsafe {
+        f(&*ptr);
+        //~^ coerce_container_to_any
+    }
+
+    // `&&Box<dyn Any>` can't be coerced to `&&dyn Any`, so only the inner reference is linted
+    let inner: &dyn Any = &x;
+    //~^ coerce_container_to_any
+    g(&inner);
+    g(&&*x);
+
+    // Auto traits of the target are reported
+    let send: Box<dyn Any + Send> = Box::new(());
+    let send_sync: Box<dyn Any + Send + Sync> = Box::new(());
+    f(&send);
+    //~^ coerce_container_to_any
+    f_send(&send);
+    //~^ coerce_container_to_any
+    f_send_sync(&send_sync);
+    //~^ coerce_container_to_any
+    f_send(&*send);
+
+    // Guards are dereferenced like any other container, but only once locked
+    let value: Box<dyn Any + Send> = Box::new(());
+    let registry: &'static Arc<Mutex<Box<dyn Any + Send>>> = Box::leak(Box::new(Arc::new(Mutex::new(value))));
+    let guard = registry.lock().unwrap();
+    f(&guard);
+    //~^ coerce_container_to_any
+    f(&*guard);
+    //~^ coerce_container_to_any
+    f(&**guard);
+    f(registry);
+    drop(guard);
+
+    // Only code that is compiled is linted
+    #[cfg(not(test))]
+    {
+        let _: &dyn Any = &x;
+        //~^ coerce_container_to_any
+    }
+    #[cfg(test)]
+    {
+        let _: &dyn Any = &x;
+    }
+    #[cfg(test)]
+    let _: &dyn Any = &undefined;
+    let _: &dyn Any = if cfg!(test) { &x } else { &() };
+    //~^ coerce_container_to_any
+
+    // The expected type reaches every branch of a conditional, and only the coerced container
+    // is linted
+    let _: &dyn Any = if cond() { &() } else { &x };
+    //~^ coerce_container_to_any
+    let _: &dyn Any = if cond() {
+        &()
+    } else if cond() {
+        ref_x
+        //~^ coerce_container_to_any
+    } else {
+        &*x
+    };
+    let _: &dyn Any = match cond() {
+        true => &x,
+        //~^ coerce_container_to_any
+        false => &(),
+    };
+    // Scrutinees of `if let` and `let`-`else` are coerced by their own expected type
+    if let Some(value) = Some::<&dyn Any>(&x) {
+        //~^ coerce_container_to_any
+        f(value);
+    }
+    let Some(_): Option<&dyn Any> = Some(if cond() { &x } else { &() }) else {
+        //~^ coerce_container_to_any
+        return;
+    };
+
+    // `Cow` is dereferenced like any other container. It can't hold a `Box<dyn Any>`, which
+    // isn't `Clone`, but it can hold an `Rc<dyn Any>`
+    let rc: Rc<dyn Any> = Rc::new(());
+    let cow: Cow<'static, Rc<dyn Any>> = Cow::Borrowed(Box::leak(Box::new(rc)));
+    f(&cow);
+    //~^ coerce_container_to_any
+    f(&*cow);
+    //~^ coerce_container_to_any
+    f(&**cow);
+
+    // Each argument gets its own suggestion
+    let y: Box<dyn Any> = Box::new(());
+    f2(&x, &y);
+    //~^ coerce_container_to_any
+    //~| coerce_container_to_any
+    f2(ref_x, &y);
+    //~^ coerce_container_to_any
+    //~| coerce_container_to_any
+
+    // The target type can come from an associated type
+    let holder = Holder(Box::new(()));
+    let _ = (&holder).get();
+    <() as Sink>::put(&x);
+    //~^ coerce_container_to_any
+
+    // No coercion happens when the container itself is expected
+    let _: &Box<dyn Any> = &x;
+    takes_box(&x);
+    takes_box(ref_x);
+    let _ = identity(&x);
+    // Generic parameters taken by value are inferred to be the reference itself, so there is no
+    // coercion to lint, and no suggestion to clash with e.g. `needless_borrow`
+    by_value(&x);
+    by_value_any(leaked);
+
+    // Containers whose deref chain doesn't reach a `dyn Any` are coerced as a whole
+    let number = Box::new(1u32);
+    f(&number);
+    let rc_number = Rc::new(Box::new(1u32));
+    f(&rc_number);
+
+    // Coercions generated by external macros are not linted
+    external! {
+        let x: Box<dyn std::any::Any> = Box::new(());
+        let _: &dyn std::any::Any = &x;
+    }
 }
 
 fn f(_: &dyn Any) {}
+
+#[cfg(not(test))]
+fn cfg_active(x: &Box<dyn Any>) {
+    f(x);
+    //~^ coerce_container_to_any
+}
+
+#[cfg(test)]
+fn cfg_inactive(x: &Box<dyn Any>) {
+    f(x);
+}
+
+// The suggestion uses canonical spacing, whatever the spacing of the source
+#[rustfmt::skip]
+#[allow(clippy::unnecessary_mut_passed)]
+fn spacing() {
+    let x: Box<dyn Any> = Box::new(());
+    let mut y: Box<dyn Any> = Box::new(());
+    let double: Box<Box<dyn Any>> = Box::new(Box::new(()));
+    f(&   x);
+    //~^ coerce_container_to_any
+    f(& mut y);
+    //~^ coerce_container_to_any
+    f(&  *  double);
+    //~^ coerce_container_to_any
+}
+
+struct AnyError;
+
+impl From<&dyn Any> for AnyError {
+    fn from(_: &dyn Any) -> Self {
+        AnyError
+    }
+}
+
+impl From<&Box<dyn Any>> for AnyError {
+    fn from(_: &Box<dyn Any>) -> Self {
+        AnyError
+    }
+}
+
+// `?` converts the error with `From::from`, which is generic, so it never coerces. Only a
+// coercion in the operand is linted, at the span the user wrote.
+fn question_mark(x: &Box<dyn Any>) -> Result<(), AnyError> {
+    Err::<(), &dyn Any>(x)?;
+    //~^ coerce_container_to_any
+    Err::<(), &Box<dyn Any>>(x)?;
+    Ok(())
+}
+
+// Downcasting the coerced reference checks the type of the container, not of its contents
+fn downcast(x: Box<dyn Any>, mut y: Box<dyn Any>, ref_x: &Box<dyn Any>) {
+    let _ = (&x as &dyn Any).downcast_ref::<u32>();
+    //~^ coerce_container_to_any
+    let _ = (&mut y as &mut dyn Any).downcast_mut::<u32>();
+    //~^ coerce_container_to_any
+    let _ = (ref_x as &dyn Any).is::<u32>();
+    //~^ coerce_container_to_any
+
+    // Not the receiver of the downcast
+    let coerced: &dyn Any = &x;
+    //~^ coerce_container_to_any
+    let _ = coerced.downcast_ref::<u32>();
+
+    // Downcasting to the container itself succeeds
+    #[allow(clippy::downcast_to_trait_object)]
+    let _ = (&x as &dyn Any).downcast_ref::<Box<dyn Any>>();
+    //~^ coerce_container_to_any
+}
+
+// Only the coerced receiver is replaced, so the method chain after it is kept
+fn method_chain(box_any: Box<dyn Any>) -> Option<u32> {
+    (&box_any as &dyn Any).downcast_ref::<u32>().copied()
+    //~^ coerce_container_to_any
+}
+
+// Not linted: `downcast_ref` is an inherent method of `dyn Any`, which method probing reaches by
+// autoderefing the receiver through the `Box`, so nothing is coerced
+#[allow(clippy::needless_borrow)]
+fn method_chain_autoref(box_any: Box<dyn Any>) -> bool {
+    (&box_any).downcast_ref::<u32>().is_some()
+}
+
+fn method_chain_ref(ref_box_any: &Box<dyn Any>) -> bool {
+    ref_box_any.downcast_ref::<u32>().is_some()
+}
+
+fn autoderef_receivers(values: &[Box<dyn Any>]) -> Option<u32> {
+    let result = std::panic::catch_unwind(|| panic!("oops"));
+    if let Err(e) = &result {
+        let _ = e.downcast_ref::<&'static str>();
+    }
+    for v in values {
+        if let Some(&n) = v.downcast_ref::<u32>() {
+            return Some(n);
+        }
+    }
+    None
+}
+
+fn f2(_: &dyn Any, _: &dyn Any) {}
+
+fn g(_: &&dyn Any) {}
+
+struct Holder(Box<dyn Any>);
+
+trait Get {
+    type Out;
+
+    fn get(self) -> Self::Out;
+}
+
+impl<'a> Get for &'a Holder {
+    type Out = &'a dyn Any;
+
+    fn get(self) -> Self::Out {
+        &self.0
+        //~^ coerce_container_to_any
+    }
+}
+
+trait Sink {
+    type In<'a>;
+
+    fn put(value: Self::In<'_>);
+}
+
+impl Sink for () {
+    type In<'a> = &'a dyn Any;
+
+    fn put(_: Self::In<'_>) {}
+}
+
+#[allow(clippy::borrowed_box)]
+fn takes_box(_: &Box<dyn Any>) {}
+
+fn by_value<T>(_: T) {}
+
+fn by_value_any<T: Any>(_: T) {}
+
+fn identity<T: ?Sized>(x: &T) -> &T {
+    x
+}
+
+fn f_send(_: &(dyn Any + Send)) {}
+
+fn f_send_sync(_: &(dyn Any + Send + Sync)) {}
+
+struct Wrapper {
+    inner: Box<Box<dyn Any>>,
+}
+
+fn get_double() -> Box<Box<dyn Any>> {
+    Box::new(Box::new(()))
+}
+
+fn cond() -> bool {
+    true
+}
+
+fn get_wrapper() -> Wrapper {
+    Wrapper {
+        inner: Box::new(Box::new(())),
+    }
+}
@@ -0,0 +1,19 @@
+#![feature(never_type)]
+#![warn(clippy::coerce_container_to_any)]
+
+// Suggestions are applied correctly in crates using feature gates
+
+use std::any::Any;
+
+fn main() {
+    let x: Box<dyn Any> = Box::new(());
+    let _: &dyn Any = &x;
+    //~^ coerce_container_to_any
+
+    let result: Result<Box<dyn Any>, !> = Ok(Box::new(()));
+    let Ok(boxed) = result;
+    f(&boxed);
+    //~^ coerce_container_to_any
+}
+
+fn f(_: &dyn Any) {}
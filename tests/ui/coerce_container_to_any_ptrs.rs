@@ -0,0 +1,105 @@
+#![warn(clippy::coerce_container_to_any)]
+
+use std::any::Any;
+use std::ops::Deref;
+use std::rc::Rc;
+use std::sync::Arc;
+
+struct Wrapper(Box<dyn Any>);
+
+impl Deref for Wrapper {
+    type Target = Box<dyn Any>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+fn main() {
+    // `Box<dyn Any>`: one `*`
+    let boxed: Box<dyn Any> = Box::new(());
+    f(&boxed);
+    //~^ coerce_container_to_any
+
+    // `Rc<dyn Any>`: one `*`
+    let rc: Rc<dyn Any> = Rc::new(());
+    f(&rc);
+    //~^ coerce_container_to_any
+
+    // `Arc<dyn Any>`: one `*`
+    let arc: Arc<dyn Any> = Arc::new(());
+    f(&arc);
+    //~^ coerce_container_to_any
+
+    // `Rc<Box<dyn Any>>`: two `*`s
+    let rc_box: Rc<Box<dyn Any>> = Rc::new(Box::new(()));
+    f(&rc_box);
+    //~^ coerce_container_to_any
+
+    // `Box<Box<dyn Any>>`: two `*`s
+    let box_box: Box<Box<dyn Any>> = Box::new(Box::new(()));
+    f(&box_box);
+    //~^ coerce_container_to_any
+
+    // `Rc<Rc<Box<dyn Any>>>`: three `*`s
+    let rc_rc_box: Rc<Rc<Box<dyn Any>>> = Rc::new(Rc::new(Box::new(())));
+    f(&rc_rc_box);
+    //~^ coerce_container_to_any
+
+    // `Arc<Box<dyn Any>>`: two `*`s
+    let arc_box: Arc<Box<dyn Any>> = Arc::new(Box::new(()));
+    f(&arc_box);
+    //~^ coerce_container_to_any
+
+    // `Box<Rc<Arc<dyn Any>>>`: three `*`s
+    let box_rc_arc: Box<Rc<Arc<dyn Any>>> = Box::new(Rc::new(Arc::new(())));
+    f(&box_rc_arc);
+    //~^ coerce_container_to_any
+
+    // A reference inside the chain, `Box<&Box<dyn Any>>`: three `*`s
+    let leaked: &'static Box<dyn Any> = Box::leak(Box::new(Box::new(()) as Box<dyn Any>));
+    let box_ref_box: Box<&Box<dyn Any>> = Box::new(leaked);
+    f(&box_ref_box);
+    //~^ coerce_container_to_any
+
+    // A custom `Deref` wrapper around a `Box<dyn Any>`: two `*`s
+    let wrapper = Wrapper(Box::new(()));
+    f(&wrapper);
+    //~^ coerce_container_to_any
+
+    // References to the containers: one more `*` each
+    let ref_rc = &rc;
+    f(ref_rc);
+    //~^ coerce_container_to_any
+    let ref_box_box = &box_box;
+    f(ref_box_box);
+    //~^ coerce_container_to_any
+    let ref_box_rc_arc = &box_rc_arc;
+    f(ref_box_rc_arc);
+    //~^ coerce_container_to_any
+    let ref_wrapper = &wrapper;
+    f(ref_wrapper);
+    //~^ coerce_container_to_any
+
+    // Partially dereferenced containers only need the remaining `*`s
+    f(&*rc_box);
+    //~^ coerce_container_to_any
+    f(&**rc_rc_box);
+    //~^ coerce_container_to_any
+    f(&*wrapper);
+    //~^ coerce_container_to_any
+
+    // Fully dereferenced containers are not linted
+    f(&*boxed);
+    f(&*rc);
+    f(&*arc);
+    f(&**rc_box);
+    f(&**box_box);
+    f(&***rc_rc_box);
+    f(&**wrapper);
+    f(&**arc_box);
+    f(&***box_rc_arc);
+    f(&***box_ref_box);
+}
+
+fn f(_: &dyn Any) {}
@@ -0,0 +1,85 @@
+#![warn(clippy::dangling_downcast_pointer)]
+
+use std::any::Any;
+use std::ptr;
+use std::rc::Rc;
+
+fn make() -> Box<dyn Any> {
+    Box::new(1u32)
+}
+
+fn make_rc() -> Rc<dyn Any> {
+    Rc::new(1u32)
+}
+
+fn make_ref() -> &'static dyn Any {
+    &1u32
+}
+
+fn cast() {
+    let ptr = (Box::new(1u32) as Box<dyn Any>).downcast_ref::<u32>().unwrap() as *const u32;
+    //~^ dangling_downcast_pointer
+    let _ = ptr;
+}
+
+fn returned() -> *const u32 {
+    make().downcast_ref::<u32>().unwrap()
+    //~^ dangling_downcast_pointer
+}
+
+fn early_return(early: bool) -> *const u32 {
+    if early {
+        return make().downcast_ref::<u32>().expect("a `u32`");
+        //~^ dangling_downcast_pointer
+    }
+    ptr::null()
+}
+
+fn from_ref_box(mut ptr: *const u32) -> *const u32 {
+    ptr = ptr::from_ref(make().downcast_ref::<u32>().unwrap());
+    //~^ dangling_downcast_pointer
+    ptr
+}
+
+fn downcast_mut() -> *mut u32 {
+    let ptr: *mut u32 = make().downcast_mut::<u32>().unwrap();
+    //~^ dangling_downcast_pointer
+    ptr
+}
+
+// The container outlives the pointer
+
+fn local() -> u32 {
+    let value = make();
+    let ptr = value.downcast_ref::<u32>().unwrap() as *const u32;
+    unsafe { *ptr }
+}
+
+fn param(value: &dyn Any) -> *const u32 {
+    value.downcast_ref::<u32>().unwrap()
+}
+
+fn temporary_reference() -> *const u32 {
+    make_ref().downcast_ref::<u32>().unwrap()
+}
+
+// Another owner of the `Rc` may keep the value alive
+
+fn from_ref(mut ptr: *const u32) -> *const u32 {
+    ptr = ptr::from_ref(make_rc().downcast_ref::<u32>().unwrap());
+    ptr
+}
+
+// The pointer is used within the statement
+
+fn same_statement() -> u32 {
+    unsafe { *(make().downcast_ref::<u32>().unwrap() as *const u32) }
+}
+
+// References are checked by the borrow checker
+
+fn copied() -> Option<u32> {
+    make().downcast_ref::<u32>().copied()
+}
+
+fn main() {}
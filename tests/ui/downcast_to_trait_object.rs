@@ -0,0 +1,33 @@
+#![warn(clippy::downcast_to_trait_object)]
+
+use std::any::Any;
+use std::fmt::Debug;
+
+fn main() {}
+
+fn by_ref(value: &dyn Any) {
+    let _ = value.downcast_ref::<Box<dyn Debug>>();
+    //~^ downcast_to_trait_object
+    let _ = value.is::<Box<dyn Debug + Send>>();
+    //~^ downcast_to_trait_object
+    let _ = value.downcast_ref::<Vec<&'static dyn Debug>>();
+    //~^ downcast_to_trait_object
+
+    // Concrete targets
+    let _ = value.downcast_ref::<u32>();
+    let _ = value.downcast_ref::<Box<u32>>();
+}
+
+fn by_mut(value: &mut (dyn Any + Send)) {
+    let _ = value.downcast_mut::<Box<dyn Fn()>>();
+    //~^ downcast_to_trait_object
+}
+
+fn boxed(value: Box<dyn Any>) {
+    let _ = value.downcast::<Box<dyn Any>>();
+    //~^ downcast_to_trait_object
+}
+
+fn generic<T: 'static>(value: &dyn Any) -> Option<&T> {
+    value.downcast_ref::<T>()
+}
@@ -0,0 +1,76 @@
+#![warn(clippy::dyn_any_fmt_wrapper)]
+
+use std::any::Any;
+use std::fmt;
+
+struct AnyDisplay<'a>(&'a dyn Any);
+
+impl fmt::Display for AnyDisplay<'_> {
+    //~^ dyn_any_fmt_wrapper
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(x) = self.0.downcast_ref::<u32>() {
+            write!(f, "{x}")
+        } else if let Some(x) = self.0.downcast_ref::<String>() {
+            write!(f, "{x}")
+        } else {
+            write!(f, "<unknown>")
+        }
+    }
+}
+
+struct BoxedDebug {
+    name: &'static str,
+    value: Box<dyn Any + Send>,
+}
+
+impl fmt::Debug for BoxedDebug {
+    //~^ dyn_any_fmt_wrapper
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = f.debug_struct("BoxedDebug");
+        s.field("name", &self.name);
+        match (self.value.downcast_ref::<i32>(), self.value.downcast_ref::<bool>()) {
+            (Some(x), _) => s.field("value", x),
+            (_, Some(x)) => s.field("value", x),
+            _ => s.field("value", &"<unknown>"),
+        };
+        s.finish()
+    }
+}
+
+// A single downcast is not a cascade
+struct SingleDowncast<'a>(&'a dyn Any);
+
+impl fmt::Display for SingleDowncast<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.downcast_ref::<u32>() {
+            Some(x) => write!(f, "{x}"),
+            None => write!(f, "<unknown>"),
+        }
+    }
+}
+
+// Other fields besides the `dyn Any` do not matter
+struct WithOtherFields(Box<dyn Any>, u32);
+
+impl fmt::Display for WithOtherFields {
+    //~^ dyn_any_fmt_wrapper
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let a = self.0.downcast_ref::<u8>().is_some();
+        let b = self.0.downcast_ref::<u16>().is_some();
+        write!(f, "{a} {b} {}", self.1)
+    }
+}
+
+// The downcasts are not on a wrapped `dyn Any`
+struct NotAny(u32);
+
+impl fmt::Display for NotAny {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let any: &dyn Any = &self.0;
+        let a = any.downcast_ref::<u8>().is_some();
+        let b = any.downcast_ref::<u16>().is_some();
+        write!(f, "{a} {b}")
+    }
+}
+
+fn main() {}
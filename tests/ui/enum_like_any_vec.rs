@@ -0,0 +1,108 @@
+#![warn(clippy::enum_like_any_vec)]
+#![allow(clippy::vec_init_then_push)]
+
+use std::any::Any;
+
+fn print_all() {
+    let mut values: Vec<Box<dyn Any>> = Vec::new();
+    //~^ enum_like_any_vec
+    values.push(Box::new(1u32));
+    values.push(Box::new(String::from("one")));
+    values.push(Box::new(2u32));
+    for value in &values {
+        if let Some(n) = value.downcast_ref::<u32>() {
+            println!("{n}");
+        } else if let Some(s) = value.downcast_ref::<String>() {
+            println!("{s}");
+        }
+    }
+}
+
+fn indexed() -> Option<u8> {
+    let mut values: Vec<Box<dyn Any + Send>> = Vec::with_capacity(3);
+    //~^ enum_like_any_vec
+    values.push(Box::new(1u8));
+    values.push(Box::new(1.0f32));
+    values.push(Box::new('a'));
+    if values[1].is::<f32>() && values.iter().any(|value| value.is::<char>()) {
+        return values.first()?.downcast_ref::<u8>().copied();
+    }
+    None
+}
+
+// Downcasts of other values don't count
+
+fn other_downcast(other: &dyn Any) -> bool {
+    let mut values: Vec<Box<dyn Any>> = Vec::new();
+    //~^ enum_like_any_vec
+    values.push(Box::new(1u32));
+    values.push(Box::new(2i64));
+    let first = &values[0];
+    other.is::<String>() && first.is::<u32>()
+}
+
+// Not all downcasts are to a pushed type
+
+fn unknown_type() {
+    let mut values: Vec<Box<dyn Any>> = Vec::new();
+    values.push(Box::new(1u32));
+    values.push(Box::new(2i64));
+    for value in &values {
+        if value.is::<String>() {
+            println!("a string");
+        }
+    }
+}
+
+// The `Vec` is passed to other code, which could store other types in it
+
+fn escapes(other: fn(&mut Vec<Box<dyn Any>>)) {
+    let mut values: Vec<Box<dyn Any>> = Vec::new();
+    values.push(Box::new(1u32));
+    values.push(Box::new(2i64));
+    other(&mut values);
+    for value in &values {
+        let _ = value.is::<u32>();
+    }
+}
+
+fn returned() -> Vec<Box<dyn Any>> {
+    let mut values: Vec<Box<dyn Any>> = Vec::new();
+    values.push(Box::new(1u32));
+    values.push(Box::new(2i64));
+    let _ = values[0].is::<u32>();
+    values
+}
+
+fn pushed_box(value: Box<dyn Any>) {
+    let mut values: Vec<Box<dyn Any>> = Vec::new();
+    values.push(Box::new(1u32));
+    values.push(value);
+    let _ = values[0].is::<u32>();
+}
+
+// A single type could be stored directly, without an enum
+
+fn single_type() {
+    let mut values: Vec<Box<dyn Any>> = Vec::new();
+    values.push(Box::new(1u32));
+    let _ = values[0].is::<u32>();
+}
+
+// Never downcast
+
+fn never_read() -> usize {
+    let mut values: Vec<Box<dyn Any>> = Vec::new();
+    values.push(Box::new(1u32));
+    values.push(Box::new(2i64));
+    values.len()
+}
+
+fn generic<T: Any>(value: T) {
+    let mut values: Vec<Box<dyn Any>> = Vec::new();
+    values.push(Box::new(1u32));
+    values.push(Box::new(value));
+    let _ = values[0].is::<u32>();
+}
+
+fn main() {}
@@ -0,0 +1,40 @@
+#![warn(clippy::generic_downcast_of_known_type)]
+
+use std::any::Any;
+
+fn parse<T: 'static>(input: &str) -> Option<T> {
+    let value: Box<dyn Any> = Box::new(input.len());
+    value.downcast::<T>().ok().map(|value| *value)
+    //~^ generic_downcast_of_known_type
+}
+
+fn checks<T: 'static>() {
+    let value = Box::new(String::new()) as Box<dyn Any + Send>;
+    let _ = value.downcast_ref::<T>();
+    //~^ generic_downcast_of_known_type
+    let _ = value.is::<T>();
+    //~^ generic_downcast_of_known_type
+
+    // Downcasts to concrete types are left to `mismatched_downcast`
+    let _ = value.downcast_ref::<String>();
+}
+
+// The box holds a `T`, so the downcast can succeed for every `T`
+fn holds_param<T: 'static, U: 'static>(x: T) {
+    let value: Box<dyn Any> = Box::new(x);
+    let _ = value.downcast_ref::<T>();
+    let _ = value.downcast_ref::<U>();
+}
+
+// Not created from a known type
+fn unknown<T: 'static>(value: Box<dyn Any>) -> Option<Box<T>> {
+    value.downcast::<T>().ok()
+}
+
+// Types containing a parameter aren't checked
+fn contains_param<T: 'static>() {
+    let value: Box<dyn Any> = Box::new(0u32);
+    let _ = value.downcast_ref::<Vec<T>>();
+}
+
+fn main() {}
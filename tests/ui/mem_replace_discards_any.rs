@@ -0,0 +1,46 @@
+#![warn(clippy::mem_replace_discards_any)]
+#![allow(clippy::mem_replace_option_with_none)]
+
+use std::any::Any;
+use std::mem;
+
+struct State {
+    value: Box<dyn Any>,
+    slot: Option<Box<dyn Any + Send>>,
+    count: Box<u32>,
+}
+
+impl State {
+    fn discard(&mut self) {
+        mem::replace(&mut self.value, Box::new(()));
+        //~^ mem_replace_discards_any
+        let _ = mem::replace(&mut self.slot, None);
+        //~^ mem_replace_discards_any
+        mem::take(&mut self.slot);
+        //~^ mem_replace_discards_any
+    }
+
+    fn keep(&mut self) {
+        let old = mem::replace(&mut self.value, Box::new(()));
+        if let Some(n) = old.downcast_ref::<u32>() {
+            println!("{n}");
+        }
+        let _slot = mem::take(&mut self.slot);
+        drop(mem::take(&mut self.slot));
+    }
+
+    fn take_old(&mut self) -> Box<dyn Any> {
+        mem::replace(&mut self.value, Box::new(()))
+    }
+
+    fn other_types(&mut self) {
+        // Not a `dyn Any`
+        mem::replace(&mut self.count, Box::new(0));
+    }
+}
+
+fn main() {
+    // Not a field
+    let mut local: Box<dyn Any> = Box::new(0u32);
+    mem::replace(&mut local, Box::new(()));
+}
@@ -0,0 +1,88 @@
+#![warn(clippy::mismatched_any_factory)]
+#![allow(clippy::vec_init_then_push)]
+
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+
+type Factory = Box<dyn Fn() -> Box<dyn Any>>;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum Slot {
+    Port,
+    Host,
+}
+
+fn main() {
+    let mut factories: HashMap<&str, Factory> = HashMap::new();
+    factories.insert("port", Box::new(|| Box::new(8080u16)));
+    factories.insert("port", Box::new(|| Box::new(String::from("8080"))));
+    //~^ mismatched_any_factory
+
+    // Different keys, and replacements returning the same type
+    factories.insert("host", Box::new(|| Box::new(String::from("localhost"))));
+    factories.insert("retries", Box::new(|| Box::new(3u8)));
+    factories.insert("retries", Box::new(|| Box::new(5u8)));
+
+    // Closures bound to locals
+    let make_host = || -> Box<dyn Any> { Box::new(String::from("localhost")) };
+    let make_ip = || -> Box<dyn Any> { Box::new([127u8, 0, 0, 1]) };
+    factories.insert("host", Box::new(make_host));
+    factories.insert("host", Box::new(make_ip));
+    //~^ mismatched_any_factory
+
+    // The returned type isn't known
+    factories.insert(
+        "host",
+        Box::new(|| {
+            let ip: Box<dyn Any> = Box::new([127u8, 0, 0, 1]);
+            ip
+        }),
+    );
+
+    let mut by_slot: BTreeMap<Slot, Box<dyn Fn() -> Box<dyn Any + Send>>> = BTreeMap::new();
+    by_slot.insert(Slot::Port, Box::new(|| Box::new(8080u16)));
+    by_slot.insert(Slot::Host, Box::new(|| Box::new(8080u32)));
+    by_slot.insert(Slot::Port, Box::new(|| Box::new(8080u32)));
+    //~^ mismatched_any_factory
+
+    let mut slots: Vec<Factory> = vec![Box::new(|| Box::new(0u8))];
+    slots[0] = Box::new(|| Box::new(1u8));
+    slots[0] = Box::new(|| Box::new(1i8));
+    //~^ mismatched_any_factory
+
+    // The index changes between the assignments
+    let mut i = 0;
+    slots[i] = Box::new(|| Box::new(1u8));
+    i += 1;
+    slots[i] = Box::new(|| Box::new(1i8));
+
+    // The factory is removed before another one is inserted under its key
+    factories.insert("timeout", Box::new(|| Box::new(30u8)));
+    factories.remove("timeout");
+    factories.insert("timeout", Box::new(|| Box::new(30u64)));
+    factories.insert("user", Box::new(|| Box::new("root")));
+    factories.clear();
+    factories.insert("user", Box::new(|| Box::new(0u32)));
+
+    // A `Vec` of factories holds one for each slot
+    let mut plugins: Vec<Factory> = Vec::new();
+    plugins.push(Box::new(|| Box::new(1u8)));
+    plugins.push(Box::new(|| Box::new("one")));
+
+    // Only factories stored in the same block are compared
+    factories.insert("mode", Box::new(|| Box::new(1u8)));
+    if plugins.is_empty() {
+        factories.insert("mode", Box::new(|| Box::new("fast")));
+    }
+
+    // Only the latest factory is replaced
+    factories.insert("level", Box::new(|| Box::new(1u8)));
+    factories.insert("level", Box::new(|| Box::new(1u16)));
+    //~^ mismatched_any_factory
+    factories.insert("level", Box::new(|| Box::new(2u16)));
+}
+
+fn generic<T: Default + 'static>(factories: &mut HashMap<u32, Factory>) {
+    factories.insert(0, Box::new(|| Box::new(T::default())));
+    factories.insert(0, Box::new(|| Box::new(0u8)));
+}
@@ -0,0 +1,70 @@
+#![warn(clippy::mismatched_downcast)]
+
+use std::any::Any;
+
+struct Foo;
+struct Bar;
+
+fn main() {
+    let value: Box<dyn Any> = Box::new(Foo);
+    let _ = value.downcast_ref::<Bar>();
+    //~^ mismatched_downcast
+    let _ = value.is::<Bar>();
+    //~^ mismatched_downcast
+    let _ = value.downcast_ref::<Foo>();
+    let _ = value.is::<Foo>();
+    let _ = value.downcast::<Bar>();
+    //~^ mismatched_downcast
+
+    let string = Box::new(String::new()) as Box<dyn Any + Send>;
+    let _ = string.downcast_ref::<&str>();
+    //~^ mismatched_downcast
+    let _ = string.downcast_ref::<String>();
+
+    let nested: Box<dyn Any> = Box::new(Box::new(Foo));
+    let _ = nested.downcast_ref::<Foo>();
+    //~^ mismatched_downcast
+    let _ = nested.downcast_ref::<Box<Foo>>();
+
+    // Primitives of different widths
+    let wide: Box<dyn Any> = Box::new(5i64);
+    let _ = wide.downcast_ref::<i32>();
+    //~^ mismatched_downcast
+    let byte: Box<dyn Any> = Box::new(1u8);
+    let _ = byte.downcast_ref::<u32>();
+    //~^ mismatched_downcast
+    let _ = byte.downcast_ref::<u8>();
+
+    // Unsuffixed literals default to `i32` and `f64`
+    let int: Box<dyn Any> = Box::new(5);
+    let _ = int.downcast_ref::<i64>();
+    //~^ mismatched_downcast
+    let _ = int.downcast_ref::<i32>();
+    let float: Box<dyn Any> = Box::new(1.5);
+    let _ = float.downcast_ref::<f32>();
+    //~^ mismatched_downcast
+    let _ = float.downcast_ref::<u32>();
+    //~^ mismatched_downcast
+
+    // Mutable locals may have been reassigned
+    let mut reassigned: Box<dyn Any> = Box::new(Foo);
+    reassigned = Box::new(Bar);
+    let _ = reassigned.downcast_ref::<Bar>();
+
+    // Not created from a known type
+    let unknown: Box<dyn Any> = make_any();
+    let _ = unknown.downcast_ref::<Bar>();
+
+    // Not a `dyn Any`
+    let concrete = Box::new(Foo);
+    let _ = (concrete as Box<dyn Any>).downcast_ref::<Bar>();
+}
+
+fn generic<T: Any>(x: T) {
+    let boxed: Box<dyn Any> = Box::new(x);
+    let _ = boxed.downcast_ref::<Foo>();
+}
+
+fn make_any() -> Box<dyn Any> {
+    Box::new(Foo)
+}
@@ -0,0 +1,32 @@
+#![warn(clippy::mismatched_downcast_annotation)]
+
+use std::any::Any;
+use std::fmt::Debug;
+
+fn main() {}
+
+fn boxed(value: Box<dyn Any>, other: Box<dyn Any>) {
+    let _n: Box<dyn Debug> = value.downcast::<u32>().unwrap();
+    //~^ mismatched_downcast_annotation
+    let _n: Box<u32> = other.downcast::<u32>().expect("not a `u32`");
+}
+
+fn by_ref(value: &dyn Any) {
+    let _n: Option<&u32> = value.downcast_ref::<u32>();
+    let _n: &dyn Debug = value.downcast_ref::<u32>().unwrap();
+    //~^ mismatched_downcast_annotation
+    let _n: &[u8] = value.downcast_ref::<[u8; 4]>().unwrap();
+    //~^ mismatched_downcast_annotation
+
+    // No turbofish or no annotation
+    let _n: &dyn Debug = value.downcast_ref::<u32>().map(|n| n as &dyn Debug).unwrap();
+    let _n: &u32 = value.downcast_ref().unwrap();
+    let _n = value.downcast_ref::<u32>().unwrap();
+    let _n: &_ = value.downcast_ref::<u32>().unwrap();
+}
+
+fn by_mut(value: &mut dyn Any) {
+    let _n: Option<&mut u32> = value.downcast_mut::<u32>();
+    let _n: &mut dyn Debug = value.downcast_mut::<u32>().unwrap();
+    //~^ mismatched_downcast_annotation
+}
@@ -0,0 +1,108 @@
+#![warn(clippy::needless_any_clone_bound)]
+#![allow(clippy::ptr_arg)]
+
+use std::any::{Any, TypeId};
+use std::fmt::Debug;
+
+fn erase<T: Any + Clone>(value: T) -> Box<dyn Any> {
+    //~^ needless_any_clone_bound
+    Box::new(value)
+}
+
+fn erase_ref<T>(value: &T) -> &dyn Any
+where
+    T: Clone + Any,
+    //~^ needless_any_clone_bound
+{
+    value
+}
+
+fn duplicate<T: Any + Clone>(value: &T) -> (T, T) {
+    //~^ needless_any_clone_bound
+    (value.clone(), value.clone())
+}
+
+fn nothing<T: Clone + Any + Debug>(value: T) {
+    //~^ needless_any_clone_bound
+    //~| needless_any_clone_bound
+    println!("{value:?}");
+}
+
+fn impl_trait(value: impl Any + Clone) -> Box<dyn Any> {
+    //~^ needless_any_clone_bound
+    Box::new(value)
+}
+
+fn to_vec<T: Any + Clone>(values: &[T]) -> Vec<T> {
+    //~^ needless_any_clone_bound
+    values.to_vec()
+}
+
+struct Registry;
+
+impl Registry {
+    fn register<T: Any + Clone>(&self, value: T) -> Box<dyn Any> {
+        //~^ needless_any_clone_bound
+        Box::new(value)
+    }
+}
+
+// Both bounds are used
+
+fn erase_clone<T: Any + Clone>(value: &T) -> Box<dyn Any> {
+    Box::new(value.clone())
+}
+
+fn type_id<T: Any + Clone>(value: &T) -> (TypeId, T) {
+    (value.type_id(), value.clone())
+}
+
+fn type_id_of<T: Any + Clone>(value: &T) -> (TypeId, T) {
+    (TypeId::of::<T>(), value.clone())
+}
+
+// Bounds that may be needed by other code are not linted
+
+fn clone_vec<T: Any + Clone>(values: &Vec<T>) -> Vec<T> {
+    values.clone()
+}
+
+fn needs_static<T: 'static>(value: T) -> T {
+    value
+}
+
+fn outlives<T: Any + Clone>(value: T) -> T {
+    needs_static(value)
+}
+
+fn generic_call<T: Any + Clone>(value: T) -> Option<T> {
+    Some(value).clone()
+}
+
+fn debug_object<T: Any + Clone + Debug>(value: T) -> Box<dyn Debug> {
+    Box::new(value)
+}
+
+fn opaque<T: Any + Clone>(value: T) -> impl Any + Clone {
+    value
+}
+
+#[derive(Clone)]
+struct Wrapper<T: Clone>(T);
+
+fn wrapper<T: Any + Clone>(value: T) -> Box<dyn Any> {
+    let wrapper = Wrapper(value);
+    Box::new(wrapper.0)
+}
+
+trait Erase {
+    fn erase<T: Any + Clone>(value: T) -> Box<dyn Any>;
+}
+
+impl Erase for Registry {
+    fn erase<T: Any + Clone>(value: T) -> Box<dyn Any> {
+        Box::new(value)
+    }
+}
+
+fn main() {}
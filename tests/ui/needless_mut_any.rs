@@ -0,0 +1,97 @@
+#![warn(clippy::needless_mut_any)]
+#![allow(clippy::borrow_deref_ref, clippy::needless_lifetimes)]
+
+use std::any::{Any, TypeId};
+
+fn downcast_ref(value: &mut dyn Any) -> Option<u32> {
+    //~^ needless_mut_any
+    value.downcast_ref::<u32>().copied()
+}
+
+fn is(value: &mut (dyn Any + Send)) -> bool {
+    //~^ needless_mut_any
+    value.is::<u32>() || value.is::<u8>()
+}
+
+fn type_id<'a>(value: &'a mut dyn Any) -> TypeId {
+    //~^ needless_mut_any
+    (*value).type_id()
+}
+
+fn pass_on(value: &mut dyn Any) -> bool {
+    //~^ needless_mut_any
+    takes_ref(value) && takes_ref(&*value)
+}
+
+fn with_lifetime_bound(value: &mut (dyn Any + 'static)) -> bool {
+    //~^ needless_mut_any
+    value.is::<u32>()
+}
+
+fn in_closure(value: &mut dyn Any) -> Option<u32> {
+    //~^ needless_mut_any
+    let get = || value.downcast_ref::<u32>().copied();
+    get()
+}
+
+// Mutated
+
+fn downcast_mut(value: &mut dyn Any) {
+    if let Some(n) = value.downcast_mut::<u32>() {
+        *n += 1;
+    }
+}
+
+fn pass_on_mut(value: &mut dyn Any) {
+    takes_mut(value);
+}
+
+fn moved(value: &mut dyn Any) -> &mut dyn Any {
+    value
+}
+
+fn mixed(value: &mut dyn Any) {
+    if value.is::<u32>() {
+        takes_mut(value);
+    }
+}
+
+// Coerced to a function pointer or passed as a callback, which need the signature
+
+fn as_fn_pointer(value: &mut dyn Any) -> bool {
+    value.is::<u32>()
+}
+
+fn as_callback(value: &mut dyn Any) -> bool {
+    value.is::<u8>()
+}
+
+fn visit_with(value: &mut dyn Any, f: impl Fn(&mut dyn Any) -> bool) -> bool {
+    f(value)
+}
+
+fn take_fns(value: &mut dyn Any) -> bool {
+    let f: fn(&mut dyn Any) -> bool = as_fn_pointer;
+    f(value) && visit_with(value, as_callback)
+}
+
+// Unused
+fn unused(_value: &mut dyn Any) {}
+
+fn takes_ref(value: &dyn Any) -> bool {
+    value.is::<u32>()
+}
+
+fn takes_mut(_: &mut dyn Any) {}
+
+trait Visit {
+    fn visit(&self, value: &mut dyn Any) -> bool;
+}
+
+impl Visit for () {
+    fn visit(&self, value: &mut dyn Any) -> bool {
+        value.is::<u32>()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,99 @@
+#![warn(clippy::panicking_any_clone)]
+
+use std::any::Any;
+use std::rc::Rc;
+
+struct Event {
+    name: String,
+    payload: Box<dyn Any>,
+}
+
+impl Clone for Event {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            payload: unimplemented!(),
+            //~^ panicking_any_clone
+        }
+    }
+}
+
+struct Message {
+    payload: Box<dyn Any + Send>,
+}
+
+impl Clone for Message {
+    fn clone(&self) -> Self {
+        panic!("`Message` can't be cloned")
+        //~^ panicking_any_clone
+    }
+}
+
+struct Job {
+    id: u32,
+    state: Box<dyn Any + Send + Sync>,
+}
+
+impl Clone for Job {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id,
+            state: unreachable!(),
+            //~^ panicking_any_clone
+        }
+    }
+}
+
+struct Command(Box<dyn Any>);
+
+impl Clone for Command {
+    fn clone(&self) -> Self {
+        todo!();
+        //~^ panicking_any_clone
+    }
+}
+
+// The payload is cloned in a different way
+struct Fallback {
+    payload: Box<dyn Any>,
+}
+
+impl Clone for Fallback {
+    fn clone(&self) -> Self {
+        Self { payload: Box::new(()) }
+    }
+}
+
+// Other fields are allowed to panic
+struct Other {
+    payload: Box<dyn Any>,
+    count: Box<u32>,
+}
+
+impl Clone for Other {
+    fn clone(&self) -> Self {
+        Self {
+            payload: Box::new(()),
+            count: unimplemented!(),
+        }
+    }
+}
+
+// `Rc<dyn Any>` can be cloned
+#[derive(Clone)]
+struct Shared {
+    payload: Rc<dyn Any>,
+}
+
+// No `dyn Any` field
+struct Plain {
+    count: u32,
+}
+
+impl Clone for Plain {
+    fn clone(&self) -> Self {
+        unimplemented!()
+    }
+}
+
+fn main() {}
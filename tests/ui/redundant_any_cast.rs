@@ -0,0 +1,31 @@
+#![warn(clippy::redundant_any_cast)]
+#![allow(clippy::coerce_container_to_any)]
+
+use std::any::Any;
+
+fn main() {
+    let boxed: Box<dyn Any> = Box::new(1u32);
+    let _ = boxed as Box<dyn Any>;
+    //~^ redundant_any_cast
+
+    let r: &dyn Any = &1u32;
+    let _ = r as &dyn Any;
+    //~^ redundant_any_cast
+    let _ = (r as &dyn Any).type_id();
+    //~^ redundant_any_cast
+
+    // Casting a `&mut` reborrows it, so it can still be used afterwards
+    let mut value = 1u32;
+    let m: &mut (dyn Any + Send) = &mut value;
+    let reborrowed = m as &mut (dyn Any + Send);
+    let _ = reborrowed.downcast_mut::<u32>();
+    let _ = m.downcast_mut::<u32>();
+
+    // These casts coerce the value
+    let _ = Box::new(1u32) as Box<dyn Any>;
+    let _ = &1u32 as &dyn Any;
+    let send: Box<dyn Any + Send> = Box::new(1u32);
+    let _ = send as Box<dyn Any>;
+    let boxed: Box<dyn Any> = Box::new(1u32);
+    let _ = &boxed as &dyn Any;
+}
@@ -0,0 +1,98 @@
+#![warn(clippy::redundant_as_any_coercion)]
+
+use std::any::Any;
+
+trait AsAny {
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+struct Cast;
+
+impl AsAny for Cast {
+    fn as_any(&self) -> &dyn Any {
+        self as &dyn Any
+        //~^ redundant_as_any_coercion
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self as &mut dyn Any
+        //~^ redundant_as_any_coercion
+    }
+}
+
+struct Reborrow;
+
+impl AsAny for Reborrow {
+    // Left to `borrow_deref_ref`
+    #[expect(clippy::borrow_deref_ref)]
+    fn as_any(&self) -> &dyn Any {
+        &*self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut *self
+        //~^ redundant_as_any_coercion
+    }
+}
+
+struct Inherent;
+
+impl Inherent {
+    fn as_send_any(&self) -> &(dyn Any + Send) {
+        self as &(dyn Any + Send)
+        //~^ redundant_as_any_coercion
+    }
+
+    fn shared_from_mut(&mut self) -> &dyn Any {
+        &*self
+        //~^ redundant_as_any_coercion
+    }
+}
+
+struct Fine;
+
+impl AsAny for Fine {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+// Dereferencing to the `dyn Any` held by `self` is not redundant
+struct Holder(Box<dyn Any>);
+
+impl AsAny for Holder {
+    fn as_any(&self) -> &dyn Any {
+        &*self.0
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut *self.0
+    }
+}
+
+impl Holder {
+    fn by_value(self) -> Box<dyn Any> {
+        Box::new(self) as Box<dyn Any>
+    }
+}
+
+macro_rules! as_any {
+    ($slf:ident) => {
+        $slf as &dyn Any
+    };
+}
+
+struct FromMacro;
+
+impl FromMacro {
+    fn as_any(&self) -> &dyn Any {
+        as_any!(self)
+    }
+}
+
+fn main() {}
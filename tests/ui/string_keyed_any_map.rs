@@ -0,0 +1,46 @@
+#![warn(clippy::string_keyed_any_map)]
+#![allow(clippy::coerce_container_to_any)]
+
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+
+fn and_then(settings: &HashMap<String, Box<dyn Any>>) -> Option<i32> {
+    settings.get("count").and_then(|v| v.downcast_ref::<i32>()).copied()
+    //~^ string_keyed_any_map
+}
+
+fn try_op(settings: &HashMap<&str, Box<dyn Any + Send>>) -> Option<String> {
+    let name = settings.get("name")?.downcast_ref::<String>()?;
+    //~^ string_keyed_any_map
+    Some(name.clone())
+}
+
+fn index(settings: &BTreeMap<String, Box<dyn Any>>) -> bool {
+    settings["verbose"].is::<bool>()
+    //~^ string_keyed_any_map
+}
+
+fn unwrap(settings: &mut HashMap<String, Box<dyn Any>>) {
+    *settings.get_mut("count").unwrap().downcast_mut::<i32>().unwrap() += 1;
+    //~^ string_keyed_any_map
+}
+
+// Keys which aren't literals may be computed for a known type
+
+fn computed_key(settings: &HashMap<String, Box<dyn Any>>, key: &str) -> Option<i32> {
+    settings.get(key)?.downcast_ref::<i32>().copied()
+}
+
+// Maps which aren't keyed by strings
+
+fn type_keyed(values: &HashMap<u32, Box<dyn Any>>) -> Option<&i32> {
+    values.get(&1)?.downcast_ref::<i32>()
+}
+
+// Maps of values which aren't `dyn Any`
+
+fn typed(values: &HashMap<String, Box<i32>>) -> Option<i32> {
+    values.get("count").map(|v| **v)
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+#![warn(clippy::thread_local_dyn_any)]
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+
+thread_local! {
+    static SLOT: RefCell<Option<Box<dyn Any>>> = const { RefCell::new(None) };
+    //~^ thread_local_dyn_any
+
+    static SEND_SLOT: RefCell<Vec<Box<dyn Any + Send>>> = RefCell::new(Vec::new());
+    //~^ thread_local_dyn_any
+
+    static TYPED: RefCell<Option<Box<u32>>> = const { RefCell::new(None) };
+    static COUNTER: Cell<u32> = const { Cell::new(0) };
+}
+
+#[allow(clippy::thread_local_dyn_any)]
+mod allowed {
+    use std::any::Any;
+    use std::cell::RefCell;
+
+    thread_local! {
+        static SLOT: RefCell<Option<Box<dyn Any>>> = const { RefCell::new(None) };
+    }
+}
+
+fn main() {
+    SLOT.with_borrow_mut(|slot| *slot = Some(Box::new(1u32)));
+    SEND_SLOT.with_borrow_mut(|slot| slot.push(Box::new(1u32)));
+    TYPED.with_borrow_mut(|slot| *slot = Some(Box::new(1)));
+    COUNTER.set(1);
+}
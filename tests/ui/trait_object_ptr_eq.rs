@@ -0,0 +1,25 @@
+#![warn(clippy::trait_object_ptr_eq)]
+
+use std::any::Any;
+use std::fmt::Debug;
+use std::ptr;
+
+fn main() {
+    let a: &dyn Any = &1u32;
+    let b: &dyn Any = &2u32;
+    let _ = ptr::eq(a, b);
+    //~^ trait_object_ptr_eq
+    let _ = std::ptr::eq(a as *const dyn Any, b as *const dyn Any);
+    //~^ trait_object_ptr_eq
+
+    let c: Box<dyn Debug + Send> = Box::new(1u32);
+    let _ = ptr::eq(&*c, &*c);
+    //~^ trait_object_ptr_eq
+
+    // Pointers to other types are not linted
+    let x = 1u32;
+    let y = 2u32;
+    let _ = ptr::eq(&x, &y);
+    let _ = ptr::eq(&[x][..], &[y][..]);
+    let _ = ptr::addr_eq(a, b);
+}
@@ -0,0 +1,13 @@
+#![warn(clippy::trait_object_ptr_eq)]
+
+use std::any::Any;
+use std::ptr;
+
+fn main() {}
+
+// `ptr::addr_eq` is not available
+#[clippy::msrv = "1.75"]
+fn msrv_1_75(a: &dyn Any, b: &dyn Any) -> bool {
+    ptr::eq(a, b)
+    //~^ trait_object_ptr_eq
+}
@@ -0,0 +1,77 @@
+#![warn(clippy::transmute_to_static_any)]
+
+use std::any::Any;
+use std::mem;
+use std::rc::Rc;
+use std::sync::Arc;
+
+struct Borrowed<'a>(&'a str);
+
+fn boxed(name: &str) -> Box<dyn Any> {
+    let name: &'static str = unsafe { mem::transmute(name) };
+    Box::new(name)
+    //~^ transmute_to_static_any
+}
+
+fn inline<'a>(value: &'a u32) -> Box<dyn Any> {
+    Box::new(unsafe { mem::transmute::<&'a u32, &'static u32>(value) })
+    //~^ transmute_to_static_any
+}
+
+fn struct_lifetime(value: Borrowed<'_>) -> Rc<dyn Any> {
+    let value: Borrowed<'static> = unsafe { mem::transmute(value) };
+    Rc::new(value)
+    //~^ transmute_to_static_any
+}
+
+fn shared(name: &str) -> Arc<dyn Any + Send + Sync> {
+    let name = unsafe { mem::transmute::<&str, &'static str>(name) };
+    Arc::new(name)
+    //~^ transmute_to_static_any
+}
+
+fn reference<'a>(value: &'a Borrowed<'a>) -> &'a dyn Any {
+    let value: &'a Borrowed<'static> = unsafe { mem::transmute(value) };
+    value
+    //~^ transmute_to_static_any
+}
+
+fn cast<'a>(value: &'a Borrowed<'a>) {
+    let value: &'a Borrowed<'static> = unsafe { mem::transmute(value) };
+    let _ = value as &dyn Any;
+    //~^ transmute_to_static_any
+}
+
+fn boxed_transmute(value: Box<Borrowed<'_>>) -> Box<dyn Any> {
+    let value: Box<Borrowed<'static>> = unsafe { mem::transmute(value) };
+    value
+    //~^ transmute_to_static_any
+}
+
+// Transmutes which are not followed by erasing the value are not linted
+
+fn extend(name: &str) -> &'static str {
+    unsafe { mem::transmute(name) }
+}
+
+fn not_erased(name: &str) -> Vec<&'static str> {
+    let name: &'static str = unsafe { mem::transmute(name) };
+    vec![name]
+}
+
+fn other_value(name: &str) -> Box<dyn Any> {
+    let _name: &'static str = unsafe { mem::transmute(name) };
+    Box::new(0u32)
+}
+
+// A transmute which changes the type itself is not about lifetimes
+
+#[repr(transparent)]
+struct Meters(u32);
+
+fn newtype(value: u32) -> Box<dyn Any> {
+    let meters: Meters = unsafe { mem::transmute(value) };
+    Box::new(meters)
+}
+
+fn main() {}
@@ -0,0 +1,64 @@
+#![warn(clippy::type_id_debug_key)]
+
+use std::any::{Any, TypeId};
+use std::collections::{BTreeSet, HashMap};
+
+struct Entry {
+    cache_key: String,
+    label: String,
+}
+
+fn binding<T: 'static>(name: &str) -> String {
+    let key = format!("{name}-{:?}", TypeId::of::<T>());
+    //~^ type_id_debug_key
+    key
+}
+
+fn map(cache: &mut HashMap<String, u32>, id: TypeId) {
+    cache.insert(format!("{id:?}"), 1);
+    //~^ type_id_debug_key
+    let _ = cache.get(&format!("{:?}", &id));
+    //~^ type_id_debug_key
+}
+
+fn set(seen: &mut BTreeSet<String>, value: &dyn Any) -> bool {
+    seen.contains(&format!("{value:?}"))
+    //~^ type_id_debug_key
+}
+
+fn field(value: Box<dyn Any>) -> Entry {
+    Entry {
+        cache_key: format!("{value:?}"),
+        //~^ type_id_debug_key
+        label: String::new(),
+    }
+}
+
+fn assign(entry: &mut Entry, id: TypeId) {
+    entry.cache_key = format!("{id:?}");
+    //~^ type_id_debug_key
+    let mut type_hash = String::new();
+    type_hash = format!("{id:?}");
+    //~^ type_id_debug_key
+    let _ = type_hash;
+}
+
+// Logging and other uses are fine
+
+fn labels(entry: &mut Entry, id: TypeId, value: &dyn Any) {
+    entry.label = format!("{id:?}");
+    let message = format!("{id:?} {value:?}");
+    println!("{message}");
+    let mut names: HashMap<u32, String> = HashMap::new();
+    names.insert(1, format!("{id:?}"));
+}
+
+// Other values in keys are fine
+
+fn other_key(cache: &mut HashMap<String, u32>, name: &str) {
+    let key = format!("{name:?}");
+    cache.insert(key, 1);
+    cache.insert(format!("{name}-1"), 1);
+}
+
+fn main() {}
@@ -31,6 +31,10 @@ fn main() {
     let _ = any_box.type_id();
     //~^ type_id_on_box
 
+    let send_box: Box<dyn Any + Send> = Box::new(0usize);
+    let _ = send_box.type_id();
+    //~^ type_id_on_box
+
     // Don't lint. We explicitly say "do this instead" if this is intentional
     let _ = TypeId::of::<Box<dyn Any>>();
     let _ = (*any_box).type_id();
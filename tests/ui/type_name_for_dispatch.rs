@@ -0,0 +1,57 @@
+#![warn(clippy::type_name_for_dispatch)]
+#![allow(clippy::match_single_binding, clippy::op_ref)]
+
+use std::any::{TypeId, type_name, type_name_of_val};
+
+fn is_u32<T>() -> bool {
+    type_name::<T>() == "u32"
+    //~^ type_name_for_dispatch
+}
+
+fn same<T, U>() -> bool {
+    std::any::type_name::<T>() == std::any::type_name::<U>()
+    //~^ type_name_for_dispatch
+}
+
+fn not_string<T>(value: &T) -> bool {
+    "alloc::string::String" != type_name_of_val(value)
+    //~^ type_name_for_dispatch
+}
+
+fn by_ref<T>() -> bool {
+    &type_name::<T>() == &"u32"
+    //~^ type_name_for_dispatch
+}
+
+fn dispatch<T>() -> u32 {
+    match type_name::<T>() {
+        //~^ type_name_for_dispatch
+        "u32" => 1,
+        "u64" | "usize" => 2,
+        _ => 0,
+    }
+}
+
+// Using type names for diagnostics is fine
+fn describe<T>() -> String {
+    format!("a value of type {}", type_name::<T>())
+}
+
+fn is_std<T>() -> bool {
+    type_name::<T>().starts_with("std::")
+}
+
+fn binding<T>() -> usize {
+    match type_name::<T>() {
+        name => name.len(),
+    }
+}
+
+fn type_id<T: 'static>() -> bool {
+    TypeId::of::<T>() == TypeId::of::<u32>()
+}
+
+fn main() {
+    // Comparisons generated by macros are not linted
+    assert_eq!(type_name::<u32>(), "u32");
+}